@@ -0,0 +1,328 @@
+use crate::bloc::common::{CommandHandler, LookupError};
+use crate::bot::{LookupBot, LookupBotX};
+use crate::callbacks::{
+    ResultSource, abbreviation_categories_keyboard, all_categories_keyboard, sources_keyboard,
+    with_sources_row,
+};
+use crate::format::LookupFormatter;
+use crate::stands4::requests::SearchAbbrsRequest;
+use crate::stands4::{AbbreviationDefinition, SliceAbbreviationsExt, Stands4Client};
+use teloxide::dptree::entry;
+use teloxide::types::InlineKeyboardMarkup;
+
+/// How many definitions of an expanded category are shown, the rest are left to the website.
+const MAX_CATEGORY_DEFINITIONS: usize = 30;
+
+/// An abbreviation to look up, optionally narrowed down to one of its categories.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AbbrQuery {
+    pub term: String,
+    pub category: Option<String>,
+}
+
+impl AbbrQuery {
+    /// Parses the abbreviation followed by an optional category, e.g. `asap` or `asap business`.
+    ///
+    /// The first word is the abbreviation, the rest of the query is the category.
+    ///
+    /// # Returns
+    ///
+    /// `Some(AbbrQuery)`, or `None` for a blank query.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let query = AbbrQuery::parse("asap business").unwrap();
+    /// assert_eq!(query.term, "asap");
+    /// assert_eq!(query.category, Some("business".to_string()));
+    /// ```
+    pub fn parse(query: &str) -> Option<Self> {
+        let mut parts = query.trim().splitn(2, char::is_whitespace);
+        let term = parts.next().filter(|term| !term.is_empty())?;
+        let category = parts
+            .next()
+            .map(str::trim)
+            .filter(|category| !category.is_empty());
+        Some(AbbrQuery {
+            term: term.to_string(),
+            category: category.map(str::to_string),
+        })
+    }
+
+    /// Finds the category the query asks for among the given ones, ignoring the case.
+    fn select<'a, 'b>(
+        &self,
+        categories: &'b [(&'a str, Vec<&'a AbbreviationDefinition>)],
+    ) -> Option<&'b (&'a str, Vec<&'a AbbreviationDefinition>)> {
+        let category = self.category.as_deref()?;
+        categories
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(category))
+    }
+}
+
+pub trait AbbrLookupBot<Response>
+where
+    Response: Send + Default,
+{
+    /// Provide the bot's response for an empty abbreviation.
+    ///
+    /// # Returns
+    ///
+    /// The default `Response` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// struct MyBot;
+    /// impl AbbrLookupBot<String> for MyBot {}
+    /// let empty = <MyBot as AbbrLookupBot<String>>::on_empty();
+    /// assert_eq!(empty, String::default());
+    /// ```
+    fn on_empty() -> Response {
+        Default::default()
+    }
+
+    /// Provide the bot's response for an abbreviation without any definitions.
+    ///
+    /// # Returns
+    ///
+    /// The default `Response` value.
+    fn on_unknown() -> Response {
+        Default::default()
+    }
+}
+
+pub trait AbbrLookupHandler {
+    /// Ask Stands4 for every definition of the abbreviation.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(Vec<AbbreviationDefinition>)` with the definitions, or `Err(LookupError::FailedRequest)`
+    /// if the remote request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: Stands4Client) -> Result<(), LookupError> {
+    /// let defs = get_abbreviations(client, AbbrQuery::parse("asap").unwrap()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn get_abbreviations(
+        client: Stands4Client,
+        query: AbbrQuery,
+    ) -> Result<Vec<AbbreviationDefinition>, LookupError> {
+        client
+            .exec(SearchAbbrsRequest { term: query.term })
+            .await
+            .map_err(|err| {
+                log::error!("Abbreviations search failed request: {:?}", err);
+                LookupError::FailedRequest
+            })
+    }
+
+    fn abbr_lookup_handler() -> CommandHandler;
+}
+
+/// Builds the keyboard that goes along the response: the category buttons for the overview,
+/// or the button leading back to the overview for an expanded category.
+fn abbr_keyboard(query: &AbbrQuery, defs: &[AbbreviationDefinition]) -> InlineKeyboardMarkup {
+    let categories = defs.categories();
+    match query.select(&categories) {
+        Some(_) => all_categories_keyboard(&query.term),
+        None => {
+            let counts = categories
+                .iter()
+                .map(|(category, defs)| (*category, defs.len()))
+                .collect::<Vec<_>>();
+            abbreviation_categories_keyboard(&query.term, &counts)
+        }
+    }
+}
+
+trait AbbrLookupFormatter<Value> {
+    fn compose_abbr_response(
+        self,
+        query: &AbbrQuery,
+        defs: &[AbbreviationDefinition],
+    ) -> Result<Value, LookupError>;
+}
+
+impl<Formatter> AbbrLookupFormatter<Formatter::Value> for Formatter
+where
+    Formatter: LookupFormatter,
+{
+    /// Compose either the overview of the abbreviation's categories or a single expanded category.
+    ///
+    /// If the query names one of the categories, its definitions are visited, up to
+    /// `MAX_CATEGORY_DEFINITIONS` of them with a link to the rest. Otherwise every category is
+    /// visited with the number of its definitions, the unfiled ones included as "Other".
+    /// An unknown category falls back to the overview.
+    ///
+    /// # Returns
+    ///
+    /// The constructed formatter value on success, or `LookupError::FailedResponseBuilder` if building fails.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let response = formatter.compose_abbr_response(&AbbrQuery::parse("asap").unwrap(), &defs)?;
+    /// ```
+    fn compose_abbr_response(
+        mut self,
+        query: &AbbrQuery,
+        defs: &[AbbreviationDefinition],
+    ) -> Result<Formatter::Value, LookupError> {
+        let categories = defs.categories();
+        match query.select(&categories) {
+            Some((category, defs)) => {
+                self.append_title(format!("Found {} abbreviations", defs.len()));
+                let shown = defs.len().min(MAX_CATEGORY_DEFINITIONS);
+                self.visit_abbreviations(0, category, &defs[..shown]);
+                if defs.len() > shown {
+                    self.append_link(self.link_provider().abbr_link(&query.term));
+                }
+            }
+            None => {
+                self.append_title(format!(
+                    "Found {} abbreviations in {} categories",
+                    defs.len(),
+                    categories.len()
+                ));
+                for (i, (category, defs)) in categories.iter().enumerate() {
+                    self.visit_abbreviation_category(i, category, defs);
+                }
+            }
+        }
+        self.build().map_err(|err| {
+            log::error!("Failed to construct a response: {:?}", err);
+            LookupError::FailedResponseBuilder
+        })
+    }
+}
+
+impl<Bot, Formatter> AbbrLookupHandler for Bot
+where
+    Bot: AbbrLookupBot<Bot::Response> + LookupBot<Formatter = Formatter> + Send + Sync + 'static,
+    Formatter: LookupFormatter<Value = Bot::Response>,
+{
+    /// Builds the handler for looking up an abbreviation, optionally within a single category.
+    ///
+    /// The handler drops empty queries, fetches the abbreviation's definitions from Stands4 and
+    /// responds with either the overview of the categories, with a button expanding each of them,
+    /// or the definitions of the requested category, with a button leading back to the overview.
+    /// Under either of them, a row of buttons switches the result to another source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let handler = Bot::abbr_lookup_handler();
+    /// // Mount `handler` into a teloxide dispatcher dptree.
+    /// ```
+    fn abbr_lookup_handler() -> CommandHandler {
+        entry()
+            .filter_async(|bot: Bot, query: String| async move {
+                bot.drop_empty(query, Bot::on_empty).await
+            })
+            .filter_map(|query: String| AbbrQuery::parse(&query))
+            .map_async(Self::get_abbreviations)
+            .filter_map_async(
+                |bot: Bot, response: Result<Vec<AbbreviationDefinition>, LookupError>| async move {
+                    bot.ensure_request_success(response).await
+                },
+            )
+            .branch(
+                teloxide::dptree::filter(|defs: Vec<AbbreviationDefinition>| defs.is_empty())
+                    .endpoint(|bot: Bot, query: AbbrQuery| async move {
+                        let keyboard = sources_keyboard(
+                            &query.term,
+                            ResultSource::Abbreviations,
+                            bot.settings(),
+                        );
+                        bot.respond_with_keyboard(Bot::on_unknown(), keyboard).await
+                    }),
+            )
+            .map(
+                move |bot: Bot, query: AbbrQuery, defs: Vec<AbbreviationDefinition>| {
+                    bot.formatter().compose_abbr_response(&query, &defs)
+                },
+            )
+            .filter_map_async(
+                |bot: Bot, response: Result<Bot::Response, LookupError>| async move {
+                    bot.retrieve_or_generic_err(response).await
+                },
+            )
+            .endpoint(
+                |bot: Bot,
+                 query: AbbrQuery,
+                 defs: Vec<AbbreviationDefinition>,
+                 response: Bot::Response| async move {
+                    let keyboard = with_sources_row(
+                        abbr_keyboard(&query, &defs),
+                        &query.term,
+                        ResultSource::Abbreviations,
+                        bot.settings(),
+                    );
+                    bot.respond_with_keyboard(response, keyboard).await
+                },
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::FullMessageFormatter;
+
+    fn abbr(definition: &str, category: &str) -> AbbreviationDefinition {
+        AbbreviationDefinition {
+            definition: definition.to_string(),
+            category: category.to_string(),
+        }
+    }
+
+    fn definitions() -> Vec<AbbreviationDefinition> {
+        vec![
+            abbr("As Soon As Possible", "UNFILED"),
+            abbr("Application Service Provider", "Computing"),
+            abbr("Active Server Pages", "Computing"),
+        ]
+    }
+
+    #[test]
+    fn query_parses_optional_category() {
+        let query = AbbrQuery::parse(" asap  ").unwrap();
+        assert_eq!(query.category, None);
+        let query = AbbrQuery::parse("asap  computer science").unwrap();
+        assert_eq!(query.term, "asap");
+        assert_eq!(query.category, Some("computer science".to_string()));
+        assert_eq!(AbbrQuery::parse("   "), None);
+    }
+
+    #[test]
+    fn overview_lists_every_category() {
+        let query = AbbrQuery::parse("asap").unwrap();
+        let response = FullMessageFormatter::default()
+            .compose_abbr_response(&query, &definitions())
+            .unwrap();
+        assert_eq!(
+            response,
+            "Found 3 abbreviations in 2 categories\n\n\\#1 in \\[Computing\\] \\- 2\n\\#2 in \\[Other\\] \\- 1\n"
+        );
+        let keyboard = abbr_keyboard(&query, &definitions());
+        assert_eq!(keyboard.inline_keyboard[0].len(), 2);
+    }
+
+    #[test]
+    fn category_is_expanded_ignoring_case() {
+        let query = AbbrQuery::parse("asap other").unwrap();
+        let response = FullMessageFormatter::default()
+            .compose_abbr_response(&query, &definitions())
+            .unwrap();
+        assert!(response.starts_with("Found 1 abbreviations\n\n\\#1 in \\[Other\\]"));
+        assert!(response.contains("As Soon As Possible"));
+        let keyboard = abbr_keyboard(&query, &definitions());
+        assert_eq!(keyboard.inline_keyboard[0][0].text, "All categories");
+    }
+}
@@ -0,0 +1,322 @@
+use crate::bloc::common::{CommandHandler, LookupError};
+use crate::bot::{LookupBot, LookupBotX};
+use crate::format::LookupFormatter;
+use crate::stands4::requests::{SearchAbbrsRequest, SearchSynoRequest, SearchWordRequest};
+use crate::stands4::{
+    AbbreviationDefinition, SliceAbbreviationsExt, Stands4Client, SynAntDefinitions, WordDefinition,
+};
+use crate::urban::requests::SearchUrbanRequest;
+use crate::urban::{UrbanDefinition, UrbanDictionaryClient};
+use teloxide::dptree::entry;
+
+/// How many entries of every source are shown, the rest are left to the respective website.
+const MAX_SECTION_ENTRIES: usize = 3;
+
+/// The results of every source looked up at once, each of them either found or failed on its own.
+#[derive(Debug, Clone)]
+pub struct Sources {
+    words: Result<Vec<WordDefinition>, LookupError>,
+    abbrs: Result<Vec<AbbreviationDefinition>, LookupError>,
+    syn_ants: Result<Vec<SynAntDefinitions>, LookupError>,
+    urban: Result<Vec<UrbanDefinition>, LookupError>,
+}
+
+impl Sources {
+    /// Whether every source answered, yet none of them knows the term.
+    fn is_empty(&self) -> bool {
+        matches!(&self.words, Ok(defs) if defs.is_empty())
+            && matches!(&self.abbrs, Ok(defs) if defs.is_empty())
+            && matches!(&self.syn_ants, Ok(defs) if defs.is_empty())
+            && matches!(&self.urban, Ok(defs) if defs.is_empty())
+    }
+}
+
+pub trait AllLookupBot<Response>
+where
+    Response: Send + Default,
+{
+    /// Provide the bot's response for an empty term.
+    ///
+    /// # Returns
+    ///
+    /// The default `Response` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// struct MyBot;
+    /// impl AllLookupBot<String> for MyBot {}
+    /// let empty = <MyBot as AllLookupBot<String>>::on_empty();
+    /// assert_eq!(empty, String::default());
+    /// ```
+    fn on_empty() -> Response {
+        Default::default()
+    }
+
+    /// Provide the bot's response for a term none of the sources knows.
+    ///
+    /// # Returns
+    ///
+    /// The default `Response` value.
+    fn on_unknown() -> Response {
+        Default::default()
+    }
+
+    /// Fits the composed response into what the bot is able to send, as the sections of every source
+    /// together may not fit.
+    ///
+    /// # Returns
+    ///
+    /// The `response` as it is.
+    fn fit_response(response: Response) -> Response {
+        response
+    }
+}
+
+pub trait AllLookupHandler {
+    /// Look the term up in Stands4 words, abbreviations and thesaurus, and in Urban Dictionary, concurrently.
+    ///
+    /// Unlike the single-source lookups, a failed request doesn't fail the whole lookup:
+    /// the error is logged and kept in place of that source's results.
+    /// Urban Dictionary is only looked up if its client is given, i.e. if the chat allows it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: Stands4Client, urban: UrbanDictionaryClient) {
+    /// let sources = get_sources(client, Some(urban), "cookie".to_string()).await;
+    /// # }
+    /// ```
+    async fn get_sources(
+        client: Stands4Client,
+        urban: Option<UrbanDictionaryClient>,
+        term: String,
+    ) -> Sources {
+        let urban = async {
+            match urban {
+                Some(urban) => urban.exec(SearchUrbanRequest { term: term.clone() }).await,
+                None => Ok(vec![]),
+            }
+        };
+        let (words, abbrs, syn_ants, urban) = futures::future::join4(
+            client.exec(SearchWordRequest { word: term.clone() }),
+            client.exec(SearchAbbrsRequest { term: term.clone() }),
+            client.exec(SearchSynoRequest { word: term.clone() }),
+            urban,
+        )
+        .await;
+        Sources {
+            words: words.map_err(|err| unavailable("Stands4 words", err)),
+            abbrs: abbrs.map_err(|err| unavailable("Stands4 abbreviations", err)),
+            syn_ants: syn_ants.map_err(|err| unavailable("Stands4 thesaurus", err)),
+            urban: urban.map_err(|err| unavailable("Urban Dictionary", err)),
+        }
+    }
+
+    fn all_lookup_handler() -> CommandHandler;
+}
+
+/// Log the failure of a single source, which is then shown as unavailable.
+fn unavailable(source: &str, err: anyhow::Error) -> LookupError {
+    log::error!("{} failed request: {:?}", source, err);
+    LookupError::FailedRequest
+}
+
+trait AllLookupFormatter<Value> {
+    fn compose_all_response(self, term: &str, sources: &Sources) -> Result<Value, LookupError>;
+}
+
+impl<Formatter> AllLookupFormatter<Formatter::Value> for Formatter
+where
+    Formatter: LookupFormatter,
+{
+    /// Compose a single response with a section per source.
+    ///
+    /// Every source that found something gets a section with up to `MAX_SECTION_ENTRIES` of its entries
+    /// and a link to the rest, a failed source gets a section noting it is unavailable,
+    /// while a source that found nothing is left out.
+    ///
+    /// # Returns
+    ///
+    /// The constructed formatter value on success, or `LookupError::FailedResponseBuilder` if building fails.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let response = formatter.compose_all_response("cookie", &sources)?;
+    /// ```
+    fn compose_all_response(
+        mut self,
+        term: &str,
+        sources: &Sources,
+    ) -> Result<Formatter::Value, LookupError> {
+        match &sources.words {
+            Ok(defs) if defs.is_empty() => {}
+            Ok(defs) => {
+                self.visit_section("Dictionary", true);
+                for (i, def) in defs.iter().take(MAX_SECTION_ENTRIES).enumerate() {
+                    self.visit_word(i, def);
+                }
+                if defs.len() > MAX_SECTION_ENTRIES {
+                    self.append_link(self.link_provider().word_link(term));
+                }
+            }
+            Err(_) => self.visit_section("Dictionary", false),
+        }
+        match &sources.abbrs {
+            Ok(defs) if defs.is_empty() => {}
+            Ok(defs) => {
+                self.visit_section("Abbreviations", true);
+                let categorized = defs.categorized();
+                for (i, (category, defs)) in
+                    categorized.iter().take(MAX_SECTION_ENTRIES).enumerate()
+                {
+                    self.visit_abbreviations(i, category, defs);
+                }
+                if categorized.len() > MAX_SECTION_ENTRIES {
+                    self.append_link(self.link_provider().abbr_link(term));
+                }
+            }
+            Err(_) => self.visit_section("Abbreviations", false),
+        }
+        match &sources.syn_ants {
+            Ok(defs) if defs.is_empty() => {}
+            Ok(defs) => {
+                self.visit_section("Thesaurus", true);
+                for (i, def) in defs.iter().take(MAX_SECTION_ENTRIES).enumerate() {
+                    self.visit_syn_ant(i, def);
+                }
+                if defs.len() > MAX_SECTION_ENTRIES {
+                    self.append_link(self.link_provider().syn_ant_link(term));
+                }
+            }
+            Err(_) => self.visit_section("Thesaurus", false),
+        }
+        match &sources.urban {
+            Ok(defs) if defs.is_empty() => {}
+            Ok(defs) => {
+                self.visit_section("Urban Dictionary", true);
+                for (i, def) in defs.iter().take(MAX_SECTION_ENTRIES).enumerate() {
+                    self.visit_urban_definition(i, def);
+                }
+                if defs.len() > MAX_SECTION_ENTRIES {
+                    self.append_link(self.link_provider().urban_link(term));
+                }
+            }
+            Err(_) => self.visit_section("Urban Dictionary", false),
+        }
+        self.build().map_err(|err| {
+            log::error!("Failed to construct a response: {:?}", err);
+            LookupError::FailedResponseBuilder
+        })
+    }
+}
+
+impl<Bot, Formatter> AllLookupHandler for Bot
+where
+    Bot: AllLookupBot<Bot::Response> + LookupBot<Formatter = Formatter> + Send + Sync + 'static,
+    Formatter: LookupFormatter<Value = Bot::Response>,
+{
+    /// Builds the handler looking a term up in every source at once.
+    ///
+    /// The handler drops empty terms, queries all the sources concurrently and responds with
+    /// one sectioned reply, where a failed source is noted instead of failing the whole response.
+    /// The reply is fitted with [`AllLookupBot::fit_response`] before it is sent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let handler = Bot::all_lookup_handler();
+    /// // Mount `handler` into a teloxide dispatcher dptree.
+    /// ```
+    fn all_lookup_handler() -> CommandHandler {
+        entry()
+            .filter_async(|bot: Bot, term: String| async move {
+                bot.drop_empty(term, Bot::on_empty).await
+            })
+            .map_async(
+                |bot: Bot, client: Stands4Client, urban: UrbanDictionaryClient, term: String| {
+                    let urban = bot.settings().urban_allowed.then_some(urban);
+                    Self::get_sources(client, urban, term)
+                },
+            )
+            .branch(
+                teloxide::dptree::filter(|sources: Sources| sources.is_empty())
+                    .endpoint(|bot: Bot| async move { bot.respond(Bot::on_unknown()).await }),
+            )
+            .map(move |bot: Bot, term: String, sources: Sources| {
+                bot.formatter()
+                    .compose_all_response(&term, &sources)
+                    .map(Bot::fit_response)
+            })
+            .filter_map_async(
+                |bot: Bot, response: Result<Bot::Response, LookupError>| async move {
+                    bot.retrieve_or_generic_err(response).await
+                },
+            )
+            .endpoint(
+                |bot: Bot, response: Bot::Response| async move { bot.respond(response).await },
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::MessageBot;
+    use crate::commands::FullMessageFormatter;
+    use crate::format::MAX_MESSAGE_LEN;
+
+    #[test]
+    fn failed_source_is_noted_and_empty_one_left_out() {
+        let sources = Sources {
+            words: Ok(vec![WordDefinition {
+                term: "cookie".to_string(),
+                definition: "a small sweet cake".to_string(),
+                part_of_speech: "noun".to_string(),
+                example: String::new(),
+            }]),
+            abbrs: Ok(vec![]),
+            syn_ants: Ok(vec![]),
+            urban: Err(LookupError::FailedRequest),
+        };
+        assert!(!sources.is_empty());
+        let response = FullMessageFormatter::default()
+            .compose_all_response("cookie", &sources)
+            .unwrap();
+        assert!(response.starts_with("*Dictionary*\n\n\\#1 \\- cookie \\(noun\\)\n"));
+        assert!(!response.contains("Abbreviations"));
+        assert!(response.ends_with("*Urban Dictionary*\n_Source unavailable_\n\n"));
+    }
+
+    #[test]
+    fn oversized_sources_are_cut_to_message_limit() {
+        let long = "a".repeat(MAX_MESSAGE_LEN / 2);
+        let sources = Sources {
+            words: Ok(vec![WordDefinition {
+                term: "cookie".to_string(),
+                definition: long.clone(),
+                part_of_speech: "noun".to_string(),
+                example: String::new(),
+            }]),
+            abbrs: Ok(vec![]),
+            syn_ants: Ok(vec![]),
+            urban: Ok(vec![
+                UrbanDefinition {
+                    word: "cookie".to_string(),
+                    meaning: long.clone(),
+                    example: None,
+                };
+                3
+            ]),
+        };
+        let response = FullMessageFormatter::default()
+            .compose_all_response("cookie", &sources)
+            .unwrap();
+        assert!(response.encode_utf16().count() > MAX_MESSAGE_LEN);
+        let response = <MessageBot as AllLookupBot<String>>::fit_response(response);
+        assert!(response.encode_utf16().count() <= MAX_MESSAGE_LEN);
+        assert!(response.starts_with("*Dictionary*\n\n\\#1 \\- cookie \\(noun\\)\n"));
+        assert!(response.ends_with("\\.\\.\\."));
+    }
+}
@@ -0,0 +1,82 @@
+use crate::bloc::common::CommandHandler;
+use crate::bloc::settings::SettingsBot;
+use crate::bot::LookupBot;
+use crate::settings::SettingsStore;
+use teloxide::dptree::entry;
+
+pub trait AutoLookupBot<Value> {
+    /// The response confirming whether single-word messages are now looked up in the chat.
+    fn auto_lookup_response(&self, enabled: bool) -> Value;
+}
+
+pub trait AutoLookupHandler {
+    fn auto_lookup_handler() -> CommandHandler;
+}
+
+/// Decides the new state of the toggle from the command's argument.
+///
+/// `on` and `off` set the toggle explicitly, anything else flips the current state.
+///
+/// # Examples
+///
+/// ```
+/// assert!(auto_lookup_state("on", false));
+/// assert!(!auto_lookup_state("", true));
+/// ```
+fn auto_lookup_state(arg: &str, current: bool) -> bool {
+    match arg.trim() {
+        "on" => true,
+        "off" => false,
+        _ => !current,
+    }
+}
+
+impl<Bot> AutoLookupHandler for Bot
+where
+    Bot: AutoLookupBot<Bot::Response>
+        + SettingsBot<Bot::Response>
+        + LookupBot
+        + Send
+        + Sync
+        + 'static,
+{
+    /// Builds the handler switching the per-chat lookup of plain single-word messages in groups.
+    ///
+    /// The new state is stored in the [`SettingsStore`] and confirmed to the chat.
+    /// Like any other setting, it may only be switched by the admins of a group.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// let handler = MessageBot::auto_lookup_handler();
+    /// ```
+    fn auto_lookup_handler() -> CommandHandler {
+        entry().endpoint(
+            |bot: Bot, settings: SettingsStore, arg: String| async move {
+                if !bot.may_change_settings().await {
+                    return bot.answer(Bot::on_forbidden()).await;
+                }
+                let settings = settings
+                    .update(bot.chat(), |settings| {
+                        settings.auto_lookup = auto_lookup_state(&arg, settings.auto_lookup)
+                    })
+                    .await;
+                bot.answer(bot.auto_lookup_response(settings.auto_lookup))
+                    .await
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn argument_sets_or_flips_the_toggle() {
+        assert!(auto_lookup_state("on", true));
+        assert!(!auto_lookup_state(" off ", true));
+        assert!(auto_lookup_state("", false));
+        assert!(!auto_lookup_state("whatever", true));
+    }
+}
@@ -0,0 +1,161 @@
+use crate::bloc::common::{CommandHandler, LookupError};
+use crate::bot::{LookupBot, LookupBotX};
+use crate::callbacks::definitions_keyboard;
+use crate::etymology::EtymologyClient;
+use crate::etymology::requests::EtymologyRequest;
+use crate::format::LookupFormatter;
+use teloxide::dptree::entry;
+
+pub trait EtymologyBot<Response>
+where
+    Response: Send + Default,
+{
+    /// Provide the bot's response for an empty word.
+    ///
+    /// # Returns
+    ///
+    /// The default `Response` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// struct MyBot;
+    /// impl EtymologyBot<String> for MyBot {}
+    /// let empty = <MyBot as EtymologyBot<String>>::on_empty();
+    /// assert_eq!(empty, String::default());
+    /// ```
+    fn on_empty() -> Response {
+        Default::default()
+    }
+
+    /// Provide the bot's response for a word whose origin is unknown.
+    ///
+    /// # Returns
+    ///
+    /// The default `Response` value.
+    fn on_unknown() -> Response {
+        Default::default()
+    }
+}
+
+pub trait EtymologyHandler {
+    /// Ask Wiktionary where the word comes from.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(Vec<String>)` with the word's etymologies, empty if there are none,
+    /// or `Err(LookupError::FailedRequest)` if the remote request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: EtymologyClient) -> Result<(), LookupError> {
+    /// let etymologies = get_etymology(client, "run".to_string()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn get_etymology(
+        client: EtymologyClient,
+        word: String,
+    ) -> Result<Vec<String>, LookupError> {
+        client
+            .exec(EtymologyRequest::new(word))
+            .await
+            .map_err(|err| {
+                log::error!("Etymology lookup failed request: {}", err);
+                LookupError::FailedRequest
+            })
+    }
+
+    fn etymology_handler() -> CommandHandler;
+}
+
+trait EtymologyFormatter<Value> {
+    fn compose_etymology(self, word: &str, etymologies: &[String]) -> Result<Value, LookupError>;
+}
+
+impl<Formatter> EtymologyFormatter<Formatter::Value> for Formatter
+where
+    Formatter: LookupFormatter,
+{
+    /// Compose a formatted response with the origins of a word.
+    ///
+    /// Appends a title `"Found N etymologies"` and visits every etymology in the order of the
+    /// Wiktionary page, then finalizes the formatter.
+    ///
+    /// # Returns
+    ///
+    /// The constructed formatter value on success, or `LookupError::FailedResponseBuilder` if building fails.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let response = formatter.compose_etymology("run", &etymologies)?;
+    /// ```
+    fn compose_etymology(
+        mut self,
+        word: &str,
+        etymologies: &[String],
+    ) -> Result<Formatter::Value, LookupError> {
+        self.append_title(format!("Found {} etymologies", etymologies.len()));
+        for (i, etymology) in etymologies.iter().enumerate() {
+            self.visit_etymology(i, word, etymology);
+        }
+        self.build().map_err(|err| {
+            log::error!("Failed to construct a response: {:?}", err);
+            LookupError::FailedResponseBuilder
+        })
+    }
+}
+
+impl<Bot, Formatter> EtymologyHandler for Bot
+where
+    Bot: EtymologyBot<Bot::Response> + LookupBot<Formatter = Formatter> + Send + Sync + 'static,
+    Formatter: LookupFormatter<Value = Bot::Response>,
+{
+    /// Builds the handler for the origin of a word.
+    ///
+    /// The handler drops empty words, asks Wiktionary about the word's etymology, formats it and
+    /// sends it back to the user with a button leading to the word's definitions.
+    /// Words without a known origin are answered with `Bot::on_unknown`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let handler = Bot::etymology_handler();
+    /// // Mount `handler` into a teloxide dispatcher dptree.
+    /// ```
+    fn etymology_handler() -> CommandHandler {
+        entry()
+            .filter_async(|bot: Bot, word: String| async move {
+                bot.drop_empty(word, Bot::on_empty).await
+            })
+            .map_async(Self::get_etymology)
+            .filter_map_async(
+                |bot: Bot, response: Result<Vec<String>, LookupError>| async move {
+                    bot.ensure_request_success(response).await
+                },
+            )
+            .branch(
+                teloxide::dptree::filter(|etymologies: Vec<String>| etymologies.is_empty())
+                    .endpoint(|bot: Bot, word: String| async move {
+                        bot.respond_with_keyboard(Bot::on_unknown(), definitions_keyboard(&word))
+                            .await
+                    }),
+            )
+            .map(move |bot: Bot, word: String, etymologies: Vec<String>| {
+                bot.formatter().compose_etymology(&word, &etymologies)
+            })
+            .filter_map_async(
+                |bot: Bot, response: Result<Bot::Response, LookupError>| async move {
+                    bot.retrieve_or_generic_err(response).await
+                },
+            )
+            .endpoint(
+                |bot: Bot, word: String, response: Bot::Response| async move {
+                    bot.respond_with_keyboard(response, definitions_keyboard(&word))
+                        .await
+                },
+            )
+    }
+}
@@ -1,7 +1,16 @@
+pub mod abbr_lookup;
+pub mod all_lookup;
+pub mod auto_lookup;
 pub mod common;
+pub mod etymology;
 pub mod formatting;
 pub mod help;
+pub mod pattern_finder;
 pub mod phrase_lookup;
+pub mod pronunciation;
+pub mod quote_lookup;
+pub mod reverse_lookup;
+pub mod settings;
 pub mod start;
 pub mod suggestions;
 pub mod teapot;
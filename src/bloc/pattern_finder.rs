@@ -0,0 +1,390 @@
+use crate::bloc::common::{CommandHandler, LookupError};
+use crate::bloc::word_finder::WordFinderFormatter;
+use crate::bot::{LookupBot, LookupBotX};
+use crate::datamuse::client::DatamuseClient;
+use crate::datamuse::request::FindWordByMaskRequest;
+use crate::datamuse::responses::Word;
+use crate::format::LookupFormatter;
+use crate::text::fold_accents;
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+use teloxide::dptree::entry;
+
+static PATTERN_FIND: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^([a-z]+)(?:(?:, *| +)([0-9]+))? *$").unwrap());
+
+/// How many words Datamuse is asked for, the most it allows.
+const MAX_CANDIDATES: u16 = 1000;
+/// How many of the matching words are shown to the user.
+const MAX_MATCHES: usize = 100;
+
+pub trait PatternFinderBot<Response>
+where
+    Response: Send + Default,
+{
+    /// Provides the default response to use when the user submits an empty pattern.
+    ///
+    /// By default this returns `Default::default()` for the response type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// struct Bot;
+    /// impl PatternFinderBot<String> for Bot {}
+    /// assert_eq!(Bot::on_empty(), String::default());
+    /// ```
+    fn on_empty() -> Response {
+        Default::default()
+    }
+
+    /// Provides the response used when the pattern or the length filter is too short or too long.
+    ///
+    /// By default this returns `Default::default()` for the response type.
+    fn on_length_invalid() -> Response {
+        Default::default()
+    }
+
+    /// Provides the response used when the pattern contains anything but letters and an optional length.
+    ///
+    /// By default this returns `Default::default()` for the response type.
+    fn on_wrong_format() -> Response {
+        Default::default()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum PatternParsingError {
+    WrongFormat,
+    InvalidLength,
+}
+
+/// A repetition pattern of a word, as seen in cryptograms.
+///
+/// Every letter of the pattern stands for some letter of the word: the same letters of the pattern
+/// must be the same in the word, different ones must differ, e.g. `abca` matches `that` and `area`.
+/// Without a length filter the pattern describes the whole word, with it the word of that length
+/// only has to contain the pattern somewhere.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LetterPattern {
+    pattern: String,
+    length: Option<usize>,
+}
+
+impl LetterPattern {
+    /// Parse a user-provided pattern, optionally followed by a length filter, e.g. `"abca"` or `"abca, 6"`.
+    ///
+    /// Validations performed:
+    /// - The pattern consists of `a`-`z` letters only, the length filter of digits only.
+    /// - The pattern must be between 2 and 15 letters long.
+    /// - The length filter must not be shorter than the pattern, nor longer than 15.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let pattern = LetterPattern::from("abca, 6".to_string()).unwrap();
+    /// assert_eq!(pattern.pattern, "abca");
+    /// assert_eq!(pattern.length, Some(6));
+    /// ```
+    fn from(pattern: String) -> Result<LetterPattern, PatternParsingError> {
+        let parsed = PATTERN_FIND
+            .captures(&pattern)
+            .ok_or(PatternParsingError::WrongFormat)?;
+
+        let letters = parsed
+            .get(1)
+            .map(|m| m.as_str())
+            .ok_or(PatternParsingError::WrongFormat)?;
+        if !(2..=15).contains(&letters.len()) {
+            return Err(PatternParsingError::InvalidLength);
+        }
+
+        let length = match parsed.get(2) {
+            Some(length) => {
+                let length = length
+                    .as_str()
+                    .parse::<usize>()
+                    .map_err(|_| PatternParsingError::InvalidLength)?;
+                if !(letters.len()..=15).contains(&length) {
+                    return Err(PatternParsingError::InvalidLength);
+                }
+                Some(length)
+            }
+            None => None,
+        };
+
+        Ok(LetterPattern {
+            pattern: letters.to_string(),
+            length,
+        })
+    }
+
+    /// Builds a Datamuse mask that lists every word of the length the pattern is looking for.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let pattern = LetterPattern::from("abca".to_string()).unwrap();
+    /// assert_eq!(pattern.datamuse_mask(), "____");
+    /// ```
+    fn datamuse_mask(&self) -> String {
+        "_".repeat(self.length.unwrap_or(self.pattern.len()))
+    }
+
+    /// Check whether the letters of `word` repeat the way the pattern describes, regardless of accents.
+    ///
+    /// With a length filter, the word only has to contain a run of letters repeating that way,
+    /// the letters around it are not checked against the pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let pattern = LetterPattern::from("abca".to_string()).unwrap();
+    /// assert!(pattern.matches("that"));
+    /// assert!(!pattern.matches("toot"));
+    /// let pattern = LetterPattern::from("abab, 6".to_string()).unwrap();
+    /// assert!(pattern.matches("banana"));
+    /// ```
+    fn matches(&self, word: &str) -> bool {
+        let pattern = self.pattern.chars().collect::<Vec<_>>();
+        let word = fold_accents(word).chars().collect::<Vec<_>>();
+        if word.len() != self.length.unwrap_or(pattern.len())
+            || word.iter().any(|char| !char.is_ascii_lowercase())
+        {
+            return false;
+        }
+        word.windows(pattern.len())
+            .any(|window| Self::is_bijection(&pattern, window))
+    }
+
+    /// Check whether `pattern` and `letters` map onto each other one-to-one, position by position.
+    fn is_bijection(pattern: &[char], letters: &[char]) -> bool {
+        let mut forward = HashMap::new();
+        let mut backward = HashMap::new();
+        pattern.iter().zip(letters).all(|(p, l)| {
+            *forward.entry(p).or_insert(l) == l && *backward.entry(l).or_insert(p) == p
+        })
+    }
+
+    /// Keep only the words that match the pattern, the most common ones first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let pattern = LetterPattern::from("abca".to_string()).unwrap();
+    /// let words = vec![Word { word: "that".into(), score: None, tags: None }];
+    /// assert_eq!(pattern.retain_only_matching(words), vec!["that".to_string()]);
+    /// ```
+    fn retain_only_matching(&self, words: Vec<Word>) -> Vec<String> {
+        let mut words = words
+            .into_iter()
+            .filter(|word| self.matches(&word.word))
+            .collect::<Vec<_>>();
+        words.sort_by(|a, b| {
+            let a = a.frequency().unwrap_or_default();
+            let b = b.frequency().unwrap_or_default();
+            b.total_cmp(&a)
+        });
+        words
+            .into_iter()
+            .take(MAX_MATCHES)
+            .map(|word| word.word)
+            .collect()
+    }
+}
+
+pub trait PatternFinderHandler {
+    /// Retrieve the words matching a `LetterPattern`.
+    ///
+    /// Datamuse is asked for as many words of the needed length as it can give,
+    /// which are then matched against the pattern locally.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(Vec<String>)` with the matching words, the most common first,
+    /// or `Err(LookupError::FailedRequest)` if the remote request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: DatamuseClient, pattern: LetterPattern) -> Result<(), LookupError> {
+    /// let words = get_matching_words(client, pattern).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn get_matching_words(
+        client: DatamuseClient,
+        pattern: LetterPattern,
+    ) -> Result<Vec<String>, LookupError> {
+        let request = FindWordByMaskRequest::new(pattern.datamuse_mask()).max(MAX_CANDIDATES);
+        client
+            .exec(request)
+            .await
+            .map(|words| pattern.retain_only_matching(words))
+            .map_err(|err| {
+                log::error!("Pattern finder failed request: {}", err);
+                LookupError::FailedRequest
+            })
+    }
+
+    async fn ensure_valid(&self, pattern: String) -> Option<LetterPattern>;
+
+    fn pattern_finder_handler() -> CommandHandler;
+}
+
+impl<Bot, Formatter> PatternFinderHandler for Bot
+where
+    Bot: PatternFinderBot<Bot::Response> + LookupBot<Formatter = Formatter> + Send + Sync + 'static,
+    Formatter: LookupFormatter<Value = Bot::Response>,
+{
+    /// Validate a pattern and send a user-facing response when it is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # async fn run<B: PatternFinderHandler>(bot: &B) {
+    /// let maybe_pattern = bot.ensure_valid("abca".to_string()).await;
+    /// # }
+    /// ```
+    async fn ensure_valid(&self, pattern: String) -> Option<LetterPattern> {
+        match LetterPattern::from(pattern) {
+            Ok(it) => Some(it),
+            Err(err) => {
+                let response = match err {
+                    PatternParsingError::WrongFormat => Self::on_wrong_format(),
+                    PatternParsingError::InvalidLength => Self::on_length_invalid(),
+                };
+                let _ = self.answer(response).await;
+                None
+            }
+        }
+    }
+
+    /// Constructs the Teloxide command handler for the letter-pattern search.
+    ///
+    /// The handler validates the pattern, looks up the matching words and renders them
+    /// the same way as the word finder does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let _handler: CommandHandler = pattern_finder_handler();
+    /// ```
+    fn pattern_finder_handler() -> CommandHandler {
+        entry()
+            .filter_async(|bot: Bot, pattern: String| async move {
+                bot.drop_empty(pattern, Self::on_empty).await
+            })
+            .filter_map_async(
+                |bot: Bot, pattern: String| async move { bot.ensure_valid(pattern).await },
+            )
+            .map_async(Self::get_matching_words)
+            .filter_map_async(
+                |bot: Bot, response: Result<Vec<String>, LookupError>| async move {
+                    bot.ensure_request_success(response).await
+                },
+            )
+            .map(move |bot: Bot, words: Vec<String>| {
+                bot.formatter().compose_word_finder_response(words)
+            })
+            .filter_map_async(
+                |bot: Bot, response: Result<Bot::Response, LookupError>| async move {
+                    bot.retrieve_or_generic_err(response).await
+                },
+            )
+            .endpoint(
+                |bot: Bot, response: Bot::Response| async move { bot.respond(response).await },
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pattern_allows_only_letters() {
+        let output = LetterPattern::from(String::from("abca"));
+        assert_eq!(
+            output,
+            Ok(LetterPattern {
+                pattern: String::from("abca"),
+                length: None,
+            })
+        );
+    }
+    #[test]
+    fn pattern_allows_length_filter() {
+        for input in ["abca 6", "abca, 6", "abca,6"] {
+            let output = LetterPattern::from(String::from(input));
+            assert_eq!(
+                output,
+                Ok(LetterPattern {
+                    pattern: String::from("abca"),
+                    length: Some(6),
+                }),
+                "{}",
+                input
+            );
+        }
+    }
+    #[test]
+    fn pattern_disallows_wrong_format() {
+        for input in ["", "ab_a", "abca, x", "12"] {
+            let output = LetterPattern::from(String::from(input));
+            assert_eq!(output, Err(PatternParsingError::WrongFormat), "{}", input);
+        }
+    }
+    #[test]
+    fn pattern_disallows_wrong_length() {
+        for input in ["a", "abcdefghijklmnop", "abca, 3", "abca, 16"] {
+            let output = LetterPattern::from(String::from(input));
+            assert_eq!(output, Err(PatternParsingError::InvalidLength), "{}", input);
+        }
+    }
+    #[test]
+    fn pattern_matches_whole_word() {
+        let pattern = LetterPattern::from(String::from("abca")).unwrap();
+        assert!(pattern.matches("that"));
+        assert!(pattern.matches("area"));
+        assert!(!pattern.matches("toot"), "letters 2 and 3 must differ");
+        assert!(!pattern.matches("tttt"), "letters 1 and 2 must differ");
+        assert!(!pattern.matches("thats"), "length must be the same");
+    }
+    #[test]
+    fn pattern_matches_part_of_word_with_length() {
+        let pattern = LetterPattern::from(String::from("abab, 6")).unwrap();
+        assert!(pattern.matches("banana"));
+        assert!(!pattern.matches("bandit"));
+        assert!(!pattern.matches("nana"), "length must be the same");
+    }
+    #[test]
+    fn pattern_matches_regardless_of_accents() {
+        let pattern = LetterPattern::from(String::from("abcd")).unwrap();
+        assert!(pattern.matches("café"));
+        assert!(!pattern.matches("ca'e"), "only letters can match");
+    }
+    #[test]
+    fn pattern_retains_most_common_first() {
+        let pattern = LetterPattern::from(String::from("abca")).unwrap();
+        let words = vec![
+            Word {
+                word: String::from("area"),
+                score: None,
+                tags: Some(vec![String::from("f:80.2")]),
+            },
+            Word {
+                word: String::from("toot"),
+                score: None,
+                tags: Some(vec![String::from("f:20.1")]),
+            },
+            Word {
+                word: String::from("that"),
+                score: None,
+                tags: Some(vec![String::from("f:5000.3")]),
+            },
+        ];
+        let retained = pattern.retain_only_matching(words);
+        assert_eq!(retained, vec![String::from("that"), String::from("area")]);
+    }
+}
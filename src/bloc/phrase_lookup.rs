@@ -1,9 +1,11 @@
 use crate::bloc::common::{CommandHandler, LookupError};
 use crate::bot::{LookupBot, LookupBotX};
+use crate::callbacks::{ResultSource, sources_keyboard};
 use crate::format::LookupFormatter;
 use crate::stands4::requests::SearchPhraseRequest;
 use crate::stands4::{PhraseDefinition, Stands4Client};
 use teloxide::dptree::entry;
+use teloxide::types::InlineKeyboardMarkup;
 
 pub trait PhraseLookupBot<Response>
 where
@@ -81,8 +83,8 @@ where
     /// Builds a formatted response for a phrase from its definitions.
     ///
     /// The formatter will append a title reporting the total number of definitions,
-    /// include up to the first five definitions, and append a phrase link when more
-    /// than five definitions are available. On success returns the formatter's
+    /// include up to the chat's `max_results` definitions, and append a phrase link when more
+    /// definitions are available. On success returns the formatter's
     /// built value; on failure returns `LookupError::FailedResponseBuilder`.
     ///
     /// # Examples
@@ -100,10 +102,11 @@ where
     ) -> Result<Formatter::Value, LookupError> {
         self.append_title(format!("Found {} definitions", defs.len()));
 
-        for (i, def) in defs.iter().take(5).enumerate() {
+        let max = self.max_results();
+        for (i, def) in defs.iter().take(max).enumerate() {
             self.visit_phrase(i, def);
         }
-        if defs.len() > 5 {
+        if defs.len() > max {
             self.append_link(self.link_provider().phrase_link(&phrase));
         }
 
@@ -121,7 +124,7 @@ where
     /// Creates the command handler pipeline that processes phrase lookup requests.
     ///
     /// The handler validates input, retrieves phrase definitions, formats a response (or a normalized error response),
-    /// and sends that response to the user.
+    /// and sends that response to the user, with buttons switching the result to another source.
     ///
     /// # Examples
     ///
@@ -134,6 +137,9 @@ where
             .filter_async(|bot: Bot, phrase: String| async move {
                 bot.drop_empty(phrase, Self::on_empty).await
             })
+            .map(|bot: Bot, phrase: String| {
+                sources_keyboard(&phrase, ResultSource::Dictionary, bot.settings())
+            })
             .map_async(Self::get_definitions)
             .filter_map_async(
                 |bot: Bot, response: Result<Vec<PhraseDefinition>, LookupError>| async move {
@@ -151,7 +157,9 @@ where
                 },
             )
             .endpoint(
-                |bot: Bot, response: Bot::Response| async move { bot.respond(response).await },
+                |bot: Bot, keyboard: InlineKeyboardMarkup, response: Bot::Response| async move {
+                    bot.respond_with_keyboard(response, keyboard).await
+                },
             )
     }
 }
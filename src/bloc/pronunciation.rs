@@ -0,0 +1,191 @@
+use crate::bloc::common::{CommandHandler, LookupError};
+use crate::bot::{LookupBot, LookupBotX};
+use crate::format::LookupFormatter;
+use crate::pronunciation::requests::PronunciationRequest;
+use crate::pronunciation::{Pronunciation, PronunciationClient};
+use teloxide::dptree::entry;
+
+pub trait PronunciationBot<Response>
+where
+    Response: Send + Default,
+{
+    /// Provide the bot's response for an empty word.
+    ///
+    /// # Returns
+    ///
+    /// The default `Response` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// struct MyBot;
+    /// impl PronunciationBot<String> for MyBot {}
+    /// let empty = <MyBot as PronunciationBot<String>>::on_empty();
+    /// assert_eq!(empty, String::default());
+    /// ```
+    fn on_empty() -> Response {
+        Default::default()
+    }
+
+    /// Provide the bot's response for a word whose pronunciation is unknown.
+    ///
+    /// # Returns
+    ///
+    /// The default `Response` value.
+    fn on_unknown() -> Response {
+        Default::default()
+    }
+
+    /// Send the recording of a word, captioned with its transcription if known.
+    fn send_audio(
+        &self,
+        word: &str,
+        audio: &str,
+        ipa: Option<&str>,
+    ) -> impl Future<Output = anyhow::Result<()>> + Send;
+}
+
+pub trait PronunciationHandler {
+    /// Ask the pronunciation source how the word is pronounced.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(Option<Pronunciation>)` with the transcription and the recording if known,
+    /// or `Err(LookupError::FailedRequest)` if the remote request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: PronunciationClient) -> Result<(), LookupError> {
+    /// let pronunciation = get_pronunciation(client, "hello".to_string()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn get_pronunciation(
+        client: PronunciationClient,
+        word: String,
+    ) -> Result<Option<Pronunciation>, LookupError> {
+        client
+            .exec(PronunciationRequest::new(word))
+            .await
+            .map_err(|err| {
+                log::error!("Pronunciation lookup failed request: {}", err);
+                LookupError::FailedRequest
+            })
+    }
+
+    async fn send_pronunciation(
+        &self,
+        word: String,
+        pronunciation: Option<Pronunciation>,
+    ) -> anyhow::Result<()>;
+
+    fn pronunciation_handler() -> CommandHandler;
+}
+
+trait PronunciationFormatter<Value> {
+    fn compose_pronunciation(self, word: &str, ipa: &str) -> Result<Value, LookupError>;
+}
+
+impl<Formatter> PronunciationFormatter<Formatter::Value> for Formatter
+where
+    Formatter: LookupFormatter,
+{
+    /// Compose a response with just the word and its IPA transcription.
+    ///
+    /// # Returns
+    ///
+    /// The constructed formatter value on success, or `LookupError::FailedResponseBuilder` if building fails.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let response = formatter.compose_pronunciation("hello", "həˈləʊ")?;
+    /// ```
+    fn compose_pronunciation(
+        mut self,
+        word: &str,
+        ipa: &str,
+    ) -> Result<Formatter::Value, LookupError> {
+        self.visit_pronunciation(word, ipa);
+        self.build().map_err(|err| {
+            log::error!("Failed to construct a response: {:?}", err);
+            LookupError::FailedResponseBuilder
+        })
+    }
+}
+
+impl<Bot, Formatter> PronunciationHandler for Bot
+where
+    Bot: PronunciationBot<Bot::Response> + LookupBot<Formatter = Formatter> + Send + Sync + 'static,
+    Formatter: LookupFormatter<Value = Bot::Response>,
+{
+    /// Sends the recording of the word, or just its transcription if there is no recording.
+    ///
+    /// Words that are not known to the pronunciation source are answered with `Bot::on_unknown`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example<B: PronunciationHandler>(bot: &B, pronunciation: Option<Pronunciation>) -> anyhow::Result<()> {
+    /// bot.send_pronunciation("hello".to_string(), pronunciation).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn send_pronunciation(
+        &self,
+        word: String,
+        pronunciation: Option<Pronunciation>,
+    ) -> anyhow::Result<()> {
+        match pronunciation {
+            Some(Pronunciation {
+                audio: Some(audio),
+                ipa,
+            }) => {
+                let sent = self.send_audio(&word, &audio, ipa.as_deref()).await;
+                if let Err(e) = sent {
+                    log::error!("Couldn't send audio: {:?}", e);
+                    let _ = self.answer_generic_err().await;
+                }
+                Ok(())
+            }
+            Some(Pronunciation { ipa: Some(ipa), .. }) => {
+                let response = self.formatter().compose_pronunciation(&word, &ipa);
+                if let Some(response) = self.retrieve_or_generic_err(response).await {
+                    self.respond(response).await?;
+                }
+                Ok(())
+            }
+            _ => self.respond(Bot::on_unknown()).await,
+        }
+    }
+
+    /// Builds the handler for pronouncing a word.
+    ///
+    /// The handler drops empty words, asks the pronunciation source about the word
+    /// and sends its recording, or at least its transcription, back to the user.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let handler = Bot::pronunciation_handler();
+    /// // Mount `handler` into a teloxide dispatcher dptree.
+    /// ```
+    fn pronunciation_handler() -> CommandHandler {
+        entry()
+            .filter_async(|bot: Bot, word: String| async move {
+                bot.drop_empty(word, Bot::on_empty).await
+            })
+            .map_async(Self::get_pronunciation)
+            .filter_map_async(
+                |bot: Bot, response: Result<Option<Pronunciation>, LookupError>| async move {
+                    bot.ensure_request_success(response).await
+                },
+            )
+            .endpoint(
+                |bot: Bot, word: String, pronunciation: Option<Pronunciation>| async move {
+                    bot.send_pronunciation(word, pronunciation).await
+                },
+            )
+    }
+}
@@ -0,0 +1,204 @@
+use crate::bloc::common::{CommandHandler, LookupError};
+use crate::bot::{LookupBot, LookupBotX};
+use crate::format::LookupFormatter;
+use crate::stands4::requests::SearchQuotesRequest;
+use crate::stands4::{QuoteDefinition, Stands4Client};
+use std::collections::HashSet;
+use teloxide::dptree::entry;
+
+/// How many quotes are shown to the user.
+const MAX_QUOTES: usize = 5;
+
+pub trait QuoteLookupBot<Response>
+where
+    Response: Send + Default,
+{
+    /// Provide the bot's response for an empty keyword.
+    ///
+    /// # Returns
+    ///
+    /// The default `Response` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// struct MyBot;
+    /// impl QuoteLookupBot<String> for MyBot {}
+    /// let empty = <MyBot as QuoteLookupBot<String>>::on_empty();
+    /// assert_eq!(empty, String::default());
+    /// ```
+    fn on_empty() -> Response {
+        Default::default()
+    }
+}
+
+pub trait QuoteLookupHandler {
+    /// Ask Stands4 for the quotes by an author of that name and for the quotes containing the keyword.
+    ///
+    /// Both searches run concurrently. The quotes by the author come first, followed by the ones
+    /// containing the keyword, with the quotes found by both searches listed once.
+    /// A failed search is logged and treated as empty, unless both of them fail.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(Vec<QuoteDefinition>)` with the found quotes, or `Err(LookupError::FailedRequest)`
+    /// if both requests fail.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: Stands4Client) -> Result<(), LookupError> {
+    /// let quotes = get_quotes(client, "mark twain".to_string()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn get_quotes(
+        client: Stands4Client,
+        query: String,
+    ) -> Result<Vec<QuoteDefinition>, LookupError> {
+        let (by_author, by_keyword) = futures::future::join(
+            client.exec(SearchQuotesRequest::by_author(query.clone())),
+            client.exec(SearchQuotesRequest::by_keyword(query)),
+        )
+        .await;
+        match (by_author, by_keyword) {
+            (Err(author_err), Err(keyword_err)) => {
+                log::error!(
+                    "Quotes search failed requests: {:?}, {:?}",
+                    author_err,
+                    keyword_err
+                );
+                Err(LookupError::FailedRequest)
+            }
+            (by_author, by_keyword) => {
+                let by_author = by_author.unwrap_or_else(|err| {
+                    log::error!("Failed to retrieve quotes by an author: {:?}", err);
+                    vec![]
+                });
+                let by_keyword = by_keyword.unwrap_or_else(|err| {
+                    log::error!("Failed to retrieve quotes by a keyword: {:?}", err);
+                    vec![]
+                });
+                Ok(merge_quotes(by_author, by_keyword))
+            }
+        }
+    }
+
+    fn quote_lookup_handler() -> CommandHandler;
+}
+
+/// Chain the quotes by the author with the quotes containing the keyword, listing each quote once.
+fn merge_quotes(
+    by_author: Vec<QuoteDefinition>,
+    by_keyword: Vec<QuoteDefinition>,
+) -> Vec<QuoteDefinition> {
+    let mut seen = HashSet::new();
+    by_author
+        .into_iter()
+        .chain(by_keyword)
+        .filter(|def| seen.insert(def.quote.clone()))
+        .collect()
+}
+
+trait QuoteLookupFormatter<Value> {
+    fn compose_quotes_response(self, quotes: Vec<QuoteDefinition>) -> Result<Value, LookupError>;
+}
+
+impl<Formatter> QuoteLookupFormatter<Formatter::Value> for Formatter
+where
+    Formatter: LookupFormatter,
+{
+    /// Compose a formatted response listing the found quotes.
+    ///
+    /// Appends a title `"Found N quotes"` and visits up to `MAX_QUOTES` quotes, then finalizes the formatter.
+    ///
+    /// # Returns
+    ///
+    /// The constructed formatter value on success, or `LookupError::FailedResponseBuilder` if building fails.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let response = formatter.compose_quotes_response(quotes)?;
+    /// ```
+    fn compose_quotes_response(
+        mut self,
+        quotes: Vec<QuoteDefinition>,
+    ) -> Result<Formatter::Value, LookupError> {
+        self.append_title(format!("Found {} quotes", quotes.len()));
+        for (i, quote) in quotes.iter().take(MAX_QUOTES).enumerate() {
+            self.visit_quote(i, quote);
+        }
+        self.build().map_err(|err| {
+            log::error!("Failed to construct a response: {:?}", err);
+            LookupError::FailedResponseBuilder
+        })
+    }
+}
+
+impl<Bot, Formatter> QuoteLookupHandler for Bot
+where
+    Bot: QuoteLookupBot<Bot::Response> + LookupBot<Formatter = Formatter> + Send + Sync + 'static,
+    Formatter: LookupFormatter<Value = Bot::Response>,
+{
+    /// Builds the handler for looking up quotes by a keyword or an author.
+    ///
+    /// The handler drops empty queries, searches Stands4 for the quotes, formats them
+    /// and sends the result back to the user.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let handler = Bot::quote_lookup_handler();
+    /// // Mount `handler` into a teloxide dispatcher dptree.
+    /// ```
+    fn quote_lookup_handler() -> CommandHandler {
+        entry()
+            .filter_async(|bot: Bot, query: String| async move {
+                bot.drop_empty(query, Bot::on_empty).await
+            })
+            .map_async(Self::get_quotes)
+            .filter_map_async(
+                |bot: Bot, response: Result<Vec<QuoteDefinition>, LookupError>| async move {
+                    bot.ensure_request_success(response).await
+                },
+            )
+            .map(move |bot: Bot, quotes: Vec<QuoteDefinition>| {
+                bot.formatter().compose_quotes_response(quotes)
+            })
+            .filter_map_async(
+                |bot: Bot, response: Result<Bot::Response, LookupError>| async move {
+                    bot.retrieve_or_generic_err(response).await
+                },
+            )
+            .endpoint(
+                |bot: Bot, response: Bot::Response| async move { bot.respond(response).await },
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quote(quote: &str, author: &str) -> QuoteDefinition {
+        QuoteDefinition {
+            quote: quote.to_string(),
+            author: author.to_string(),
+        }
+    }
+
+    #[test]
+    fn quotes_by_author_come_first_without_duplicates() {
+        let by_author = vec![quote("a", "Twain"), quote("b", "Twain")];
+        let by_keyword = vec![quote("c", "Wilde"), quote("a", "Twain")];
+        assert_eq!(
+            merge_quotes(by_author, by_keyword),
+            vec![
+                quote("a", "Twain"),
+                quote("b", "Twain"),
+                quote("c", "Wilde")
+            ]
+        );
+    }
+}
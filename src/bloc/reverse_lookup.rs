@@ -0,0 +1,191 @@
+use crate::bloc::common::{CommandHandler, LookupError};
+use crate::bot::{LookupBot, LookupBotX};
+use crate::datamuse::client::DatamuseClient;
+use crate::datamuse::request::FindWordByMeaningRequest;
+use crate::format::LookupFormatter;
+use crate::stands4::requests::SearchWordRequest;
+use crate::stands4::{Stands4Client, WordDefinition};
+use futures::TryFutureExt;
+use teloxide::dptree::entry;
+
+/// How many of the best matches are shown to the user.
+const MAX_MATCHES: usize = 10;
+/// How many of the best matches are accompanied with a definition from Stands4.
+const MAX_DEFINED_MATCHES: usize = 3;
+
+#[derive(Debug, Clone)]
+pub struct ReverseMatch {
+    word: String,
+    definition: Option<WordDefinition>,
+}
+
+pub trait ReverseLookupBot<Response>
+where
+    Response: Send + Default,
+{
+    /// Provide the bot's response for an empty description.
+    ///
+    /// # Returns
+    ///
+    /// The default `Response` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// struct MyBot;
+    /// impl ReverseLookupBot<String> for MyBot {}
+    /// let empty = <MyBot as ReverseLookupBot<String>>::on_empty();
+    /// assert_eq!(empty, String::default());
+    /// ```
+    fn on_empty() -> Response {
+        Default::default()
+    }
+}
+
+pub trait ReverseLookupHandler {
+    /// Ask Datamuse for the words whose meaning matches the given description.
+    ///
+    /// The candidates are ranked by Datamuse's relevance score, best first, and only the
+    /// first `MAX_MATCHES` are kept.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(Vec<String>)` with the ranked candidates, or `Err(LookupError::FailedRequest)`
+    /// if the remote request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: DatamuseClient) -> Result<(), LookupError> {
+    /// let words = get_candidates(client, "a person who hoards books".to_string()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn get_candidates(
+        client: DatamuseClient,
+        description: String,
+    ) -> Result<Vec<String>, LookupError> {
+        client
+            .exec_ranked(FindWordByMeaningRequest::new(description))
+            .await
+            .map(|words| words.into_iter().take(MAX_MATCHES).collect())
+            .map_err(|err| {
+                log::error!("Reverse lookup failed request: {}", err);
+                LookupError::FailedRequest
+            })
+    }
+
+    /// Attach the first Stands4 definition to each of the top `MAX_DEFINED_MATCHES` candidates.
+    ///
+    /// The definitions are requested concurrently; a failed or empty lookup leaves the candidate
+    /// without a definition instead of failing the whole response.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: Stands4Client) {
+    /// let matches = define_candidates(client, vec!["bibliophile".to_string()]).await;
+    /// # }
+    /// ```
+    async fn define_candidates(client: Stands4Client, words: Vec<String>) -> Vec<ReverseMatch> {
+        let defined = words.iter().take(MAX_DEFINED_MATCHES).map(|word| {
+            client
+                .exec(SearchWordRequest { word: word.clone() })
+                .map_ok(|defs| defs.into_iter().next())
+                .unwrap_or_else(|err| {
+                    log::error!("Failed to retrieve definitions of a word: {:?}", err);
+                    None
+                })
+        });
+        let definitions = futures::future::join_all(defined).await;
+
+        words
+            .into_iter()
+            .enumerate()
+            .map(|(i, word)| ReverseMatch {
+                word,
+                definition: definitions.get(i).cloned().flatten(),
+            })
+            .collect()
+    }
+
+    fn reverse_lookup_handler() -> CommandHandler;
+}
+
+trait ReverseLookupFormatter<Value> {
+    fn compose_reverse_response(self, matches: Vec<ReverseMatch>) -> Result<Value, LookupError>;
+}
+
+impl<Formatter> ReverseLookupFormatter<Formatter::Value> for Formatter
+where
+    Formatter: LookupFormatter,
+{
+    /// Compose a formatted response listing the words that match a description.
+    ///
+    /// Appends a title `"Found N words"` and visits every match in rank order, then finalizes the formatter.
+    ///
+    /// # Returns
+    ///
+    /// The constructed formatter value on success, or `LookupError::FailedResponseBuilder` if building fails.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let response = formatter.compose_reverse_response(matches)?;
+    /// ```
+    fn compose_reverse_response(
+        mut self,
+        matches: Vec<ReverseMatch>,
+    ) -> Result<Formatter::Value, LookupError> {
+        self.append_title(format!("Found {} words", matches.len()));
+        for (i, m) in matches.iter().enumerate() {
+            self.visit_reverse_match(i, &m.word, m.definition.as_ref());
+        }
+        self.build().map_err(|err| {
+            log::error!("Failed to construct a response: {:?}", err);
+            LookupError::FailedResponseBuilder
+        })
+    }
+}
+
+impl<Bot, Formatter> ReverseLookupHandler for Bot
+where
+    Bot: ReverseLookupBot<Bot::Response> + LookupBot<Formatter = Formatter> + Send + Sync + 'static,
+    Formatter: LookupFormatter<Value = Bot::Response>,
+{
+    /// Builds the handler for the reverse dictionary: from a description to matching words.
+    ///
+    /// The handler drops empty descriptions, ranks candidates via Datamuse, defines the best
+    /// of them via Stands4, formats the result and sends it back to the user.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let handler = Bot::reverse_lookup_handler();
+    /// // Mount `handler` into a teloxide dispatcher dptree.
+    /// ```
+    fn reverse_lookup_handler() -> CommandHandler {
+        entry()
+            .filter_async(|bot: Bot, description: String| async move {
+                bot.drop_empty(description, Bot::on_empty).await
+            })
+            .map_async(Self::get_candidates)
+            .filter_map_async(
+                |bot: Bot, response: Result<Vec<String>, LookupError>| async move {
+                    bot.ensure_request_success(response).await
+                },
+            )
+            .map_async(Self::define_candidates)
+            .map(move |bot: Bot, matches: Vec<ReverseMatch>| {
+                bot.formatter().compose_reverse_response(matches)
+            })
+            .filter_map_async(
+                |bot: Bot, response: Result<Bot::Response, LookupError>| async move {
+                    bot.retrieve_or_generic_err(response).await
+                },
+            )
+            .endpoint(
+                |bot: Bot, response: Bot::Response| async move { bot.respond(response).await },
+            )
+    }
+}
@@ -0,0 +1,109 @@
+use crate::bloc::common::CommandHandler;
+use crate::bot::{LookupBot, LookupBotX};
+use crate::callbacks::settings_keyboard;
+use crate::settings::{Setting, SettingsStore};
+use teloxide::dptree::entry;
+use teloxide::prelude::Requester;
+use teloxide::types::{ChatId, UserId};
+
+pub trait SettingsBot<Response> {
+    /// The chat whose settings are shown and changed.
+    fn chat(&self) -> ChatId;
+
+    /// Whether the user asking may change the chat's settings, see [`may_change_settings`].
+    fn may_change_settings(&self) -> impl Future<Output = bool> + Send;
+
+    /// The text going along the settings menu.
+    fn settings_response() -> Response;
+
+    /// The text explaining that only the chat's admins can change its settings.
+    fn on_forbidden() -> Response;
+}
+
+/// Checks whether the user may change the settings of the chat, which affect everyone in it.
+///
+/// Anyone may change the settings of their private chat with the bot, while in groups only the admins may.
+/// A user who can't be told apart, or whose membership can't be checked, may not.
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn example(bot: teloxide::Bot) {
+/// assert!(may_change_settings(&bot, ChatId(42), Some(UserId(42))).await);
+/// # }
+/// ```
+pub async fn may_change_settings(bot: &teloxide::Bot, chat: ChatId, user: Option<UserId>) -> bool {
+    let Some(user) = user else {
+        return false;
+    };
+    if chat.is_user() {
+        return true;
+    }
+    match bot.get_chat_member(chat, user).await {
+        Ok(member) => member.is_privileged(),
+        Err(err) => {
+            log::error!(
+                "Failed to check whether {} administers {}: {:?}",
+                user,
+                chat,
+                err
+            );
+            false
+        }
+    }
+}
+
+pub trait SettingsHandler {
+    fn settings_handler() -> CommandHandler;
+
+    fn change_setting_handler() -> CommandHandler;
+}
+
+impl<Bot> SettingsHandler for Bot
+where
+    Bot: SettingsBot<Bot::Response> + LookupBot + Send + Sync + 'static,
+{
+    /// Builds the handler showing the chat's settings as a menu of buttons, one per setting.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// let handler = MessageBot::settings_handler();
+    /// ```
+    fn settings_handler() -> CommandHandler {
+        entry().endpoint(|bot: Bot| async move {
+            let keyboard = settings_keyboard(bot.settings());
+            bot.respond_with_keyboard(Bot::settings_response(), keyboard)
+                .await
+        })
+    }
+
+    /// Builds the handler for a pressed settings button: the setting is moved on to its next value,
+    /// saved in the [`SettingsStore`] and the menu is updated to show it.
+    ///
+    /// In groups only the admins may change the settings, see [`may_change_settings`], anyone else
+    /// gets the unchanged menu along with [`SettingsBot::on_forbidden`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// let handler = CallbackBot::change_setting_handler();
+    /// ```
+    fn change_setting_handler() -> CommandHandler {
+        entry().endpoint(
+            |bot: Bot, store: SettingsStore, setting: Setting| async move {
+                if !bot.may_change_settings().await {
+                    let keyboard = settings_keyboard(bot.settings());
+                    return bot
+                        .respond_with_keyboard(Bot::on_forbidden(), keyboard)
+                        .await;
+                }
+                let settings = store
+                    .update(bot.chat(), |settings| settings.cycle(setting))
+                    .await;
+                bot.respond_with_keyboard(Bot::settings_response(), settings_keyboard(&settings))
+                    .await
+            },
+        )
+    }
+}
@@ -94,6 +94,48 @@ impl SuggestionOwner for ThesaurusSuggestion {
     }
 }
 
+struct QuoteSuggestion;
+impl SuggestionOwner for QuoteSuggestion {
+    /// Builds an inline query article that instructs the user to send `q.KEYWORD` to look up quotes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let result = QuoteSuggestion.produce();
+    /// assert!(matches!(result, Some(InlineQueryResult::Article(_))));
+    /// ```
+    fn produce(self) -> Option<InlineQueryResult> {
+        let text = "Or write \"q.KEYWORD\" to look up quotes by a keyword or an author";
+        let msg = InputMessageContentText::new(
+            "Write @WordsLookupBot \"q.KEYWORD\" to look up quotes by a keyword or an author",
+        );
+        let msg = InputMessageContent::Text(msg);
+        let msg = InlineQueryResultArticle::new("quote", text, msg);
+        Some(InlineQueryResult::Article(msg))
+    }
+}
+
+struct AbbrSuggestion;
+impl SuggestionOwner for AbbrSuggestion {
+    /// Builds an inline query article that instructs the user to send `ab.ABBR` to look up an abbreviation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let result = AbbrSuggestion.produce();
+    /// assert!(matches!(result, Some(InlineQueryResult::Article(_))));
+    /// ```
+    fn produce(self) -> Option<InlineQueryResult> {
+        let text = "Or write \"ab.ABBR\" to look up an abbreviation by categories";
+        let msg = InputMessageContentText::new(
+            "Write @WordsLookupBot \"ab.ABBR\" to look up what an abbreviation stands for, category by category",
+        );
+        let msg = InputMessageContent::Text(msg);
+        let msg = InlineQueryResultArticle::new("abbr", text, msg);
+        Some(InlineQueryResult::Article(msg))
+    }
+}
+
 struct WordleSuggestion {
     wordle: Option<WordleDayAnswer>,
 }
@@ -125,7 +167,7 @@ impl WordleSuggestion {
         );
         formatter.append_title(wordle_title);
         formatter
-            .compose_word_defs(&solution, &answer.definitions)
+            .compose_word_defs(&solution, &answer.definitions, None)
             .ok()
     }
 
@@ -196,6 +238,27 @@ impl SuggestionOwner for WordFinderSuggestion {
     }
 }
 
+struct ClueSuggestion;
+impl SuggestionOwner for ClueSuggestion {
+    /// Produces an inline-article suggestion prompting the user to use `c.MASK, CLUE` to solve a crossword clue.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let suggestion = ClueSuggestion;
+    /// assert!(suggestion.produce().is_some());
+    /// ```
+    fn produce(self) -> Option<InlineQueryResult> {
+        let text = "Or write \"c.C_T, CLUE\" to solve a crossword clue";
+        let msg = InputMessageContentText::new(
+            "Write @WordsLookupBot \"c.C_T, CLUE\" to find words matching both blanks and a clue",
+        );
+        let msg = InputMessageContent::Text(msg);
+        let msg = InlineQueryResultArticle::new("clue", text, msg);
+        Some(InlineQueryResult::Article(msg))
+    }
+}
+
 pub trait SuggestionsBot {}
 pub trait SuggestionsHandler {
     /// Attempts to obtain a fresh WordleDayAnswer from the provided cache.
@@ -226,7 +289,7 @@ pub trait SuggestionsHandler {
 
     /// Send a set of inline suggestion articles in response to an inline query.
     ///
-    /// Builds Help, UrbanDictionary, Thesaurus suggestions and, if available, a Wordle suggestion;
+    /// Builds Help, UrbanDictionary, Thesaurus, Quote, Abbreviation suggestions and, if available, a Wordle suggestion;
     /// filters out any missing suggestions and answers the inline query with the resulting articles.
     ///
     /// The `wordle` argument supplies an optional WordleDayAnswer; if `Some`, a Wordle suggestion will
@@ -255,7 +318,7 @@ where
 {
     /// Send inline query suggestions assembled from the available suggestion owners.
     ///
-    /// The handler gathers Help, Urban, Thesaurus, and (optionally) Wordle suggestions,
+    /// The handler gathers Help, Urban, Thesaurus, Quote, Abbreviation, and (optionally) Wordle suggestions,
     /// filters out any missing entries, and forwards the collected InlineQueryResult
     /// list to the bot's inline answer responder.
     ///
@@ -283,7 +346,10 @@ where
             WordleSuggestion { wordle }.produce(),
             UrbanSuggestion.produce(),
             ThesaurusSuggestion.produce(),
+            QuoteSuggestion.produce(),
+            AbbrSuggestion.produce(),
             WordFinderSuggestion.produce(),
+            ClueSuggestion.produce(),
         ];
         let answers = suggestions.into_iter().flatten().collect::<Vec<_>>();
         self.answer(answers).await?;
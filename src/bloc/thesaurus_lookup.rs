@@ -1,9 +1,11 @@
 use crate::bloc::common::{CommandHandler, LookupError};
 use crate::bot::{LookupBot, LookupBotX};
+use crate::callbacks::{ResultSource, sources_keyboard};
 use crate::format::LookupFormatter;
 use crate::stands4::requests::SearchSynoRequest;
 use crate::stands4::{Stands4Client, SynAntDefinitions};
 use teloxide::dptree::entry;
+use teloxide::types::InlineKeyboardMarkup;
 
 pub trait ThesaurusLookupBot<Response>
 where
@@ -73,8 +75,8 @@ where
     /// Builds a formatted thesaurus response for a term from a list of synonym/antonym definitions.
     ///
     /// The formatter will append a title indicating how many definitions were found, include up to
-    /// the first five definitions via `visit_syn_ant`, and append a link to additional definitions if
-    /// more than five were returned. Returns the formatter's built value or `LookupError::FailedResponseBuilder`
+    /// the chat's `max_results` definitions via `visit_syn_ant`, and append a link to additional definitions if
+    /// more were returned. Returns the formatter's built value or `LookupError::FailedResponseBuilder`
     /// if the builder fails.
     ///
     /// # Returns
@@ -97,10 +99,11 @@ where
             "Found {} different definitions with respective information",
             defs.len()
         ));
-        for (i, def) in defs.iter().take(5).enumerate() {
+        let max = self.max_results();
+        for (i, def) in defs.iter().take(max).enumerate() {
             self.visit_syn_ant(i, def);
         }
-        if defs.len() > 5 {
+        if defs.len() > max {
             self.append_link(self.link_provider().syn_ant_link(&term));
         }
 
@@ -123,7 +126,8 @@ where
     /// Builds a teloxide command handler that performs the complete thesaurus lookup flow.
     ///
     /// The handler validates the incoming phrase, obtains synonym/antonym definitions,
-    /// formats a response using the bot's formatter, and sends the resulting response.
+    /// formats a response using the bot's formatter, and sends the resulting response
+    /// with buttons switching the result to another source.
     ///
     /// # Examples
     ///
@@ -137,6 +141,9 @@ where
             .filter_async(|bot: Bot, phrase: String| async move {
                 bot.drop_empty(phrase, Self::on_empty).await
             })
+            .map(|bot: Bot, phrase: String| {
+                sources_keyboard(&phrase, ResultSource::Thesaurus, bot.settings())
+            })
             .map_async(Self::get_definitions)
             .filter_map_async(
                 |bot: Bot, response: Result<Vec<SynAntDefinitions>, LookupError>| async move {
@@ -154,7 +161,9 @@ where
                 },
             )
             .endpoint(
-                |bot: Bot, response: Bot::Response| async move { bot.respond(response).await },
+                |bot: Bot, keyboard: InlineKeyboardMarkup, response: Bot::Response| async move {
+                    bot.respond_with_keyboard(response, keyboard).await
+                },
             )
     }
 }
\ No newline at end of file
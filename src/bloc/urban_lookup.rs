@@ -1,9 +1,11 @@
 use crate::bloc::common::{CommandHandler, LookupError};
 use crate::bot::{LookupBot, LookupBotX};
+use crate::callbacks::{ResultSource, sources_keyboard};
 use crate::format::LookupFormatter;
 use crate::urban::requests::SearchUrbanRequest;
 use crate::urban::{UrbanDefinition, UrbanDictionaryClient};
 use teloxide::dptree::entry;
+use teloxide::types::InlineKeyboardMarkup;
 
 pub trait UrbanLookupBot<Response>
 where
@@ -26,6 +28,15 @@ where
     fn on_empty() -> Response {
         Response::default()
     }
+
+    /// Provides the response returned when the chat has switched Urban Dictionary off.
+    ///
+    /// # Returns
+    ///
+    /// A `Response` value constructed with `Response::default()`.
+    fn on_disabled() -> Response {
+        Response::default()
+    }
 }
 
 pub trait UrbanLookupHandler {
@@ -72,7 +83,7 @@ where
 {
     /// Compose a formatted response for Urban Dictionary search results.
     ///
-    /// The response includes a title stating the total number of definitions, up to the first five definitions, and — when more than five definitions exist — a link to the full Urban Dictionary entry for the term.
+    /// The response includes a title stating the total number of definitions, up to the chat's `max_results` definitions, and — when more definitions exist — a link to the full Urban Dictionary entry for the term.
     ///
     /// # Returns
     ///
@@ -95,10 +106,11 @@ where
             defs.len()
         ));
 
-        for (i, def) in defs.iter().take(5).enumerate() {
+        let max = self.max_results();
+        for (i, def) in defs.iter().take(max).enumerate() {
             self.visit_urban_definition(i, def);
         }
-        if defs.len() > 5 {
+        if defs.len() > max {
             self.append_link(self.link_provider().urban_link(&term))
         }
         self.build().map_err(|err| {
@@ -115,6 +127,9 @@ where
 {
     /// Creates a Teloxide command handler that processes Urban Dictionary lookups by validating the input phrase, retrieving definitions, formatting a response, and sending it via the bot.
     ///
+    /// Nothing is looked up in a chat that has switched Urban Dictionary off in its settings.
+    /// The response comes with buttons switching the result to another source.
+    ///
     /// # Examples
     ///
     /// ```
@@ -126,6 +141,16 @@ where
             .filter_async(|bot: Bot, phrase: String| async move {
                 bot.drop_empty(phrase, Self::on_empty).await
             })
+            .filter_async(|bot: Bot| async move {
+                if bot.settings().urban_allowed {
+                    return true;
+                }
+                let _ = bot.respond(Self::on_disabled()).await;
+                false
+            })
+            .map(|bot: Bot, phrase: String| {
+                sources_keyboard(&phrase, ResultSource::Urban, bot.settings())
+            })
             .map_async(Self::get_definitions)
             .filter_map_async(
                 |bot: Bot, response: Result<Vec<UrbanDefinition>, LookupError>| async move {
@@ -143,7 +168,9 @@ where
                 },
             )
             .endpoint(
-                |bot: Bot, response: Bot::Response| async move { bot.respond(response).await },
+                |bot: Bot, keyboard: InlineKeyboardMarkup, response: Bot::Response| async move {
+                    bot.respond_with_keyboard(response, keyboard).await
+                },
             )
     }
 }
\ No newline at end of file
@@ -1,15 +1,43 @@
 use crate::bloc::common::{CommandHandler, LookupError};
 use crate::bot::{LookupBot, LookupBotX};
 use crate::datamuse::client::DatamuseClient;
-use crate::datamuse::request::FindWordByMaskRequest;
+use crate::datamuse::request::{FindWordByClueRequest, FindWordByMaskRequest};
+use crate::datamuse::responses::Word;
 use crate::format::LookupFormatter;
+use crate::text::{WORD_CHARS, fold_accents};
 use regex::Regex;
 use std::collections::HashSet;
 use std::sync::LazyLock;
 use teloxide::dptree::entry;
 
-static WORD_FIND: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new("^([a-z_]+),? ?([a-z]*)$").unwrap());
+static WORD_FIND: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^([a-z_*#@\[\]]+),? ?([a-z]*)((?: +[a-z_]+=[a-z0-9.]*)*)$").unwrap()
+});
+static CLUE_FIND: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"^([a-z_*#@\[\]]+), *([{WORD_CHARS}][{WORD_CHARS} ]*?) *$"
+    ))
+    .unwrap()
+});
+
+const VOWELS: &str = "aeiou";
+const CONSONANTS: &str = "bcdfghjklmnpqrstvwxyz";
+
+/// Tells whether plain text, sent without `/find` or `f.`, is meant as a finder mask.
+///
+/// Only the wildcards `_` and `*` make a mask out of plain text. Letter classes, `#` and `@`
+/// are understood inside a mask, but on their own they are much more likely part of an email,
+/// a mention or a hashtag.
+///
+/// # Examples
+///
+/// ```
+/// assert!(looks_like_mask("c[au]#_"));
+/// assert!(!looks_like_mask("john@example.com"));
+/// ```
+pub fn looks_like_mask(text: &str) -> bool {
+    text.contains(['_', '*'])
+}
 
 pub trait WordFinderBot<Response>
 where
@@ -31,6 +59,22 @@ where
         Default::default()
     }
 
+    /// Provides the response to use when the user submits an empty crossword clue.
+    ///
+    /// By default this returns `Default::default()` for the response type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// struct Bot;
+    /// impl word_finder::WordFinderBot<String> for Bot {}
+    /// let resp = Bot::on_empty_clue();
+    /// assert_eq!(resp, String::default());
+    /// ```
+    fn on_empty_clue() -> Response {
+        Default::default()
+    }
+
     /// Provides the default response used when the user-supplied mask has an invalid length.
     ///
     /// Returns the default `Response` value.
@@ -92,8 +136,12 @@ where
 pub trait WordFinderHandler {
     /// Retrieve candidate words from Datamuse that match a given `FinderMask`.
     ///
-    /// The request uses `mask.mask` as the Datamuse mask (where `'_'` denotes unknown letters),
-    /// and the resulting list is filtered to exclude words containing any characters from
+    /// When the mask carries a crossword clue, Datamuse is asked for words fitting both the mask
+    /// and the clue's meaning, and those are ranked by how well they match the clue instead.
+    ///
+    /// The request uses `mask.datamuse_mask()` as the Datamuse mask (where `'_'` denotes unknown letters),
+    /// the resulting list is narrowed down to the words satisfying the letter classes Datamuse can't express, is ranked according to the mask's options (most common words first,
+    /// unless asked otherwise) and filtered to exclude words containing any characters from
     /// `mask.banned`.
     ///
     /// # Examples
@@ -116,10 +164,19 @@ pub trait WordFinderHandler {
         client: DatamuseClient,
         mask: FinderMask,
     ) -> Result<Vec<String>, LookupError> {
-        client
-            .exec(FindWordByMaskRequest::new(mask.mask.clone()))
-            .await
-            .map(|vec| mask.retain_only_allowed(vec))
+        let words = match &mask.hint {
+            Some(hint) => {
+                let request = FindWordByClueRequest::new(mask.datamuse_mask(), hint.clone());
+                client.exec_ranked(request).await
+            }
+            None => {
+                let request = FindWordByMaskRequest::new(mask.datamuse_mask());
+                client.exec(request).await.map(|words| mask.rank(words))
+            }
+        };
+        words
+            .map(|words| mask.retain_only_matching(words))
+            .map(|words| mask.retain_only_allowed(words))
             .map_err(|err| {
                 log::error!("WF failed request: {}", err);
                 LookupError::FailedRequest
@@ -128,10 +185,16 @@ pub trait WordFinderHandler {
 
     async fn ensure_valid(&self, mask: String) -> Option<FinderMask>;
 
+    async fn ensure_valid_clue(&self, clue: String) -> Option<FinderMask>;
+
     fn word_finder_handler() -> CommandHandler;
+
+    fn clue_finder_handler() -> CommandHandler;
+
+    fn find_words_handler() -> CommandHandler;
 }
 
-trait WordFinderFormatter<Value> {
+pub(crate) trait WordFinderFormatter<Value> {
     fn compose_word_finder_response(self, defs: Vec<String>) -> Result<Value, LookupError>;
 }
 
@@ -170,9 +233,27 @@ where
 }
 
 #[derive(Debug, Clone, PartialEq)]
+enum MaskToken {
+    Letter(char),
+    AnyLetter,
+    AnyLetters,
+    OneOf(String),
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+enum FinderOrder {
+    #[default]
+    Frequency,
+    Alphabetical,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct FinderMask {
     mask: String,
     banned: String,
+    order: FinderOrder,
+    min_freq: Option<f64>,
+    hint: Option<String>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -186,12 +267,15 @@ impl FinderMask {
     /// Parse a user-provided mask string into a `FinderMask`, validating format, lengths, and content.
     ///
     /// The input may include an optional comma-separated banlist (e.g. `"a__ow, jfk"`) or consist of only the mask (`"a__ow"`).
+    /// Both may be followed by `key=value` options, see [`FinderMask::parse_options`].
     /// Validations performed:
-    /// - Mask length must be between 2 and 15 characters.
+    /// - Mask must be made of the symbols described in [`FinderMask::tokenize`].
+    /// - Mask must describe between 2 and 15 letter positions, not counting `*`.
     /// - Banlist length must be at most 13 characters.
-    /// - Mask must contain at least one underscore (`'_'`) and at least one non-underscore character.
+    /// - Mask must contain at least one wildcard and at least one known letter.
     ///
     /// On success returns a `FinderMask` with `mask` set to the parsed mask and `banned` set to the parsed banlist (or an empty string when absent).
+    /// Accents are stripped off both beforehand, so `caf_` and `café` style masks are the same.
     ///
     /// # Examples
     ///
@@ -201,6 +285,7 @@ impl FinderMask {
     /// assert_eq!(fm.banned, "jfk");
     /// ```
     fn from(mask: String) -> Result<FinderMask, MaskParsingError> {
+        let mask = fold_accents(&mask);
         let parsed = WORD_FIND
             .captures(&mask)
             .ok_or(MaskParsingError::WrongFormat)?;
@@ -209,34 +294,281 @@ impl FinderMask {
             .get(1)
             .map(|m| m.as_str())
             .ok_or(MaskParsingError::WrongFormat)?;
-        if finder_mask.len() < 2 || finder_mask.len() > 15 {
-            return Err(MaskParsingError::InvalidLength);
-        }
+        let tokens = Self::tokenize(finder_mask)?;
+        Self::ensure_positions(&tokens)?;
 
         let banned_list = parsed.get(2).map(|m| m.as_str()).unwrap_or("");
         if banned_list.len() > 13 {
             return Err(MaskParsingError::InvalidLength);
         }
 
-        let (has_blank, has_filled) = finder_mask
-            .chars()
-            .fold((false, false), |(has_blank, has_filled), char| {
-                (has_blank || char == '_', has_filled || char != '_')
-            });
+        let (has_blank, has_filled) = tokens.iter().fold(
+            (false, false),
+            |(has_blank, has_filled), token| match token {
+                MaskToken::Letter(_) => (has_blank, true),
+                _ => (true, has_filled),
+            },
+        );
         if !has_blank || !has_filled {
             return Err(MaskParsingError::InvalidQuery);
         }
 
+        let options = parsed.get(3).map(|m| m.as_str()).unwrap_or("");
+        let (order, min_freq) = Self::parse_options(options)?;
+
         let combo = FinderMask {
             mask: finder_mask.to_string(),
             banned: banned_list.to_string(),
+            order,
+            min_freq,
+            hint: None,
         };
         Ok(combo)
     }
 
+    /// Parse a crossword clue, i.e. a mask followed by a comma and a free-text hint, into a `FinderMask`.
+    ///
+    /// The mask accepts the same symbols and length limits as in [`FinderMask::from`], but it doesn't need to
+    /// contain any known letters, since the hint narrows the search down on its own. A clue carries neither a banlist
+    /// nor any options, as the matches are always ranked by how close they are to the hint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let fm = FinderMask::with_hint("c_t, feline pet".to_string()).unwrap();
+    /// assert_eq!(fm.mask, "c_t");
+    /// assert_eq!(fm.hint, Some("feline pet".to_string()));
+    /// ```
+    fn with_hint(clue: String) -> Result<FinderMask, MaskParsingError> {
+        let clue = fold_accents(&clue);
+        let parsed = CLUE_FIND
+            .captures(&clue)
+            .ok_or(MaskParsingError::WrongFormat)?;
+
+        let finder_mask = parsed
+            .get(1)
+            .map(|m| m.as_str())
+            .ok_or(MaskParsingError::WrongFormat)?;
+        let tokens = Self::tokenize(finder_mask)?;
+        Self::ensure_positions(&tokens)?;
+        if tokens
+            .iter()
+            .all(|token| matches!(token, MaskToken::Letter(_)))
+        {
+            return Err(MaskParsingError::InvalidQuery);
+        }
+
+        let hint = parsed
+            .get(2)
+            .map(|m| m.as_str())
+            .ok_or(MaskParsingError::WrongFormat)?;
+
+        Ok(FinderMask {
+            mask: finder_mask.to_string(),
+            hint: Some(hint.to_string()),
+            ..Default::default()
+        })
+    }
+
+    /// Ensure the mask describes between 2 and 15 letter positions, not counting `*`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let tokens = FinderMask::tokenize("*a").unwrap();
+    /// assert_eq!(FinderMask::ensure_positions(&tokens), Err(MaskParsingError::InvalidLength));
+    /// ```
+    fn ensure_positions(tokens: &[MaskToken]) -> Result<(), MaskParsingError> {
+        let positions = tokens
+            .iter()
+            .filter(|token| **token != MaskToken::AnyLetters)
+            .count();
+        match positions {
+            2..=15 => Ok(()),
+            _ => Err(MaskParsingError::InvalidLength),
+        }
+    }
+
+    /// Parse the whitespace-separated `key=value` options that may follow the mask.
+    ///
+    /// Supported options:
+    /// - `sort=alpha` lists words alphabetically, `sort=freq` (the default) lists the most common words first;
+    /// - `min_freq=N` drops words used less than `N` times per million words of English text.
+    ///
+    /// Any other key, an unparsable value or a frequency that isn't a finite number, like `nan` or `inf`,
+    /// results in `MaskParsingError::WrongFormat`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let (order, min_freq) = FinderMask::parse_options(" sort=alpha min_freq=2.5").unwrap();
+    /// assert_eq!(order, FinderOrder::Alphabetical);
+    /// assert_eq!(min_freq, Some(2.5));
+    /// ```
+    fn parse_options(options: &str) -> Result<(FinderOrder, Option<f64>), MaskParsingError> {
+        let mut order = FinderOrder::default();
+        let mut min_freq = None;
+        for option in options.split_whitespace() {
+            match option.split_once('=') {
+                Some(("sort", "alpha")) => order = FinderOrder::Alphabetical,
+                Some(("sort", "freq")) => order = FinderOrder::Frequency,
+                Some(("min_freq", value)) => {
+                    let value = value
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|value| value.is_finite())
+                        .ok_or(MaskParsingError::WrongFormat)?;
+                    min_freq = Some(value);
+                }
+                _ => return Err(MaskParsingError::WrongFormat),
+            }
+        }
+        Ok((order, min_freq))
+    }
+
+    /// Split a mask into the positions it describes.
+    ///
+    /// Supported symbols:
+    /// - `a`-`z` stand for themselves;
+    /// - `_` stands for any single letter;
+    /// - `*` stands for any number of letters, including none;
+    /// - `[abc]` stands for a single letter out of the listed ones;
+    /// - `#` stands for any consonant and `@` for any vowel.
+    ///
+    /// Unclosed or empty letter classes, as well as any other symbol, result in `MaskParsingError::WrongFormat`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let tokens = FinderMask::tokenize("c[au]#*").unwrap();
+    /// assert_eq!(tokens.len(), 4);
+    /// ```
+    fn tokenize(mask: &str) -> Result<Vec<MaskToken>, MaskParsingError> {
+        let mut tokens = vec![];
+        let mut chars = mask.chars();
+        while let Some(char) = chars.next() {
+            let token = match char {
+                '_' => MaskToken::AnyLetter,
+                '*' => MaskToken::AnyLetters,
+                '#' => MaskToken::OneOf(CONSONANTS.to_string()),
+                '@' => MaskToken::OneOf(VOWELS.to_string()),
+                '[' => {
+                    let mut class = String::new();
+                    loop {
+                        match chars.next() {
+                            Some(']') => break,
+                            Some(char) if char.is_ascii_lowercase() => class.push(char),
+                            _ => return Err(MaskParsingError::WrongFormat),
+                        }
+                    }
+                    if class.is_empty() {
+                        return Err(MaskParsingError::WrongFormat);
+                    }
+                    MaskToken::OneOf(class)
+                }
+                char if char.is_ascii_lowercase() => MaskToken::Letter(char),
+                _ => return Err(MaskParsingError::WrongFormat),
+            };
+            tokens.push(token);
+        }
+        Ok(tokens)
+    }
+
+    /// Translate the mask into the closest pattern Datamuse understands.
+    ///
+    /// Letters and `*` are kept as they are, every single-letter position (`_`, `[abc]`, `#`, `@`)
+    /// becomes a `_`, so the results have to be narrowed down with [`FinderMask::retain_only_matching`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mask = FinderMask::from("un*[ai]ble".to_string()).unwrap();
+    /// assert_eq!(mask.datamuse_mask(), "un*_ble");
+    /// ```
+    fn datamuse_mask(&self) -> String {
+        Self::tokenize(&self.mask)
+            .unwrap_or_default()
+            .iter()
+            .map(|token| match token {
+                MaskToken::Letter(char) => *char,
+                MaskToken::AnyLetters => '*',
+                MaskToken::AnyLetter | MaskToken::OneOf(_) => '_',
+            })
+            .collect()
+    }
+
+    /// Keep only the words that match the mask exactly, letter classes included.
+    ///
+    /// Datamuse only knows about single-letter and any-length wildcards, and may also suggest
+    /// multi-word expressions, so every candidate is checked against the full mask locally.
+    /// Accents are ignored, so `caf_` matches `café`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mask = FinderMask::from("c@t".to_string()).unwrap();
+    /// let words = vec!["cat".to_string(), "cyt".to_string()];
+    /// assert_eq!(mask.retain_only_matching(words), vec!["cat".to_string()]);
+    /// ```
+    fn retain_only_matching(&self, vec: Vec<String>) -> Vec<String> {
+        let pattern = Self::tokenize(&self.mask)
+            .unwrap_or_default()
+            .iter()
+            .map(|token| match token {
+                MaskToken::Letter(char) => char.to_string(),
+                MaskToken::AnyLetter => "[a-z]".to_string(),
+                MaskToken::AnyLetters => "[a-z]*".to_string(),
+                MaskToken::OneOf(class) => format!("[{}]", class),
+            })
+            .collect::<String>();
+        match Regex::new(format!("^{}$", pattern).as_str()).ok() {
+            Some(matcher) => vec
+                .into_iter()
+                .filter(|it| matcher.is_match(&fold_accents(it)))
+                .collect(),
+            None => vec,
+        }
+    }
+
+    /// Order the words returned by Datamuse according to the mask's options.
+    ///
+    /// Words below `min_freq` are dropped (a word without frequency data counts as never used),
+    /// the rest are sorted either by descending frequency, keeping Datamuse's order for ties,
+    /// or alphabetically.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mask = FinderMask::from("___ly".to_string()).unwrap();
+    /// let words = vec![
+    ///     Word { word: "ugly".into(), score: None, tags: Some(vec!["f:27.4".into()]) },
+    ///     Word { word: "only".into(), score: None, tags: Some(vec!["f:1225.5".into()]) },
+    /// ];
+    /// assert_eq!(mask.rank(words), vec!["only".to_string(), "ugly".to_string()]);
+    /// ```
+    fn rank(&self, words: Vec<Word>) -> Vec<String> {
+        let mut words = words
+            .into_iter()
+            .filter(|word| {
+                self.min_freq
+                    .is_none_or(|min| word.frequency().unwrap_or_default() >= min)
+            })
+            .collect::<Vec<_>>();
+        match self.order {
+            FinderOrder::Frequency => words.sort_by(|a, b| {
+                let a = a.frequency().unwrap_or_default();
+                let b = b.frequency().unwrap_or_default();
+                b.total_cmp(&a)
+            }),
+            FinderOrder::Alphabetical => words.sort_by(|a, b| a.word.cmp(&b.word)),
+        }
+        words.into_iter().map(|word| word.word).collect()
+    }
+
     /// Filter candidate words by removing any that contain characters from `self.banned`.
     ///
-    /// The method returns a new vector containing only the input words that do not include any banned character.
+    /// The method returns a new vector containing only the input words that do not include any banned character,
+    /// regardless of accents, so banning `e` also bans `é`.
     /// If the input vector is empty or the banlist cannot be compiled into a regex, the original vector is returned.
     ///
     /// # Examples
@@ -261,7 +593,7 @@ impl FinderMask {
         match Regex::new(format!("[{}]", banned.as_str()).as_str()).ok() {
             Some(bans) => vec
                 .into_iter()
-                .filter(|it| bans.find(it).is_none())
+                .filter(|it| bans.find(&fold_accents(it)).is_none())
                 .collect(),
             None => vec,
         }
@@ -294,12 +626,28 @@ where
         match FinderMask::from(mask) {
             Ok(it) => Some(it),
             Err(err) => {
-                let response = match err {
-                    MaskParsingError::WrongFormat => Self::on_wrong_format(),
-                    MaskParsingError::InvalidLength => Self::on_length_invalid(),
-                    MaskParsingError::InvalidQuery => Self::on_invalid_query(),
-                };
-                let _ = self.answer(response).await;
+                let _ = self.answer(mask_error_response::<Self, _>(err)).await;
+                None
+            }
+        }
+    }
+
+    /// Validate a crossword clue and send a user-facing response when it is invalid.
+    ///
+    /// Same as [`WordFinderHandler::ensure_valid`], but parses the input with [`FinderMask::with_hint`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # async fn run<B: crate::bloc::word_finder::WordFinderBot<B::Response> + Send + Sync + 'static>(bot: &B) {
+    /// let maybe_mask = bot.ensure_valid_clue("c_t, feline pet".to_string()).await;
+    /// # }
+    /// ```
+    async fn ensure_valid_clue(&self, clue: String) -> Option<FinderMask> {
+        match FinderMask::with_hint(clue) {
+            Ok(it) => Some(it),
+            Err(err) => {
+                let _ = self.answer(mask_error_response::<Self, _>(err)).await;
                 None
             }
         }
@@ -322,6 +670,43 @@ where
                 bot.drop_empty(mask, Self::on_empty).await
             })
             .filter_map_async(|bot: Bot, mask: String| async move { bot.ensure_valid(mask).await })
+            .chain(Self::find_words_handler())
+    }
+
+    /// Constructs the Teloxide command handler for the crossword clue helper.
+    ///
+    /// Works the same way as [`WordFinderHandler::word_finder_handler`], except that the input
+    /// is parsed as a mask with a meaning hint, e.g. `c_t, feline pet`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let _handler: CommandHandler = clue_finder_handler();
+    /// ```
+    fn clue_finder_handler() -> CommandHandler {
+        entry()
+            .filter_async(|bot: Bot, clue: String| async move {
+                bot.drop_empty(clue, Self::on_empty_clue).await
+            })
+            .filter_map_async(
+                |bot: Bot, clue: String| async move { bot.ensure_valid_clue(clue).await },
+            )
+            .chain(Self::find_words_handler())
+    }
+
+    /// Constructs the part of the finder handlers shared by masks and clues.
+    ///
+    /// Given a valid `FinderMask`, it looks the words up, formats them and sends the response.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let handler = entry()
+    ///     .map(|| FinderMask::default())
+    ///     .chain(Bot::find_words_handler());
+    /// ```
+    fn find_words_handler() -> CommandHandler {
+        entry()
             .map_async(Self::get_possible_words)
             .filter_map_async(
                 |bot: Bot, response: Result<Vec<String>, LookupError>| async move {
@@ -342,6 +727,25 @@ where
     }
 }
 
+/// Pick the user-facing response describing why a mask couldn't be parsed.
+///
+/// # Examples
+///
+/// ```
+/// let response: String = mask_error_response::<MessageBot, _>(MaskParsingError::InvalidLength);
+/// ```
+fn mask_error_response<Bot, Response>(err: MaskParsingError) -> Response
+where
+    Bot: WordFinderBot<Response>,
+    Response: Send + Default,
+{
+    match err {
+        MaskParsingError::WrongFormat => Bot::on_wrong_format(),
+        MaskParsingError::InvalidLength => Bot::on_length_invalid(),
+        MaskParsingError::InvalidQuery => Bot::on_invalid_query(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -402,6 +806,7 @@ mod tests {
             Ok(FinderMask {
                 mask: String::from("a__ow"),
                 banned: String::from(""),
+                ..Default::default()
             })
         );
     }
@@ -414,6 +819,7 @@ mod tests {
             Ok(FinderMask {
                 mask: String::from("a__ow"),
                 banned: String::from(""),
+                ..Default::default()
             })
         );
     }
@@ -426,6 +832,7 @@ mod tests {
             Ok(FinderMask {
                 mask: String::from("a__ow"),
                 banned: String::from("jfk"),
+                ..Default::default()
             })
         );
     }
@@ -435,6 +842,7 @@ mod tests {
         let mask = FinderMask {
             mask: String::from(""),
             banned: String::from(""),
+            ..Default::default()
         };
         let words = Vec::<String>::new();
         let retained = mask.retain_only_allowed(words.clone());
@@ -455,6 +863,7 @@ mod tests {
         let mask = FinderMask {
             mask: String::from(""),
             banned: String::from(""),
+            ..Default::default()
         };
         let words = vec![String::from("abra"), String::from("cadabra")];
         let retained = mask.retain_only_allowed(words.clone());
@@ -465,6 +874,7 @@ mod tests {
         let mask = FinderMask {
             mask: String::from(""),
             banned: String::from("abcdr"),
+            ..Default::default()
         };
         let words = vec![String::from("abra"), String::from("cadabra")];
         let retained = mask.retain_only_allowed(words);
@@ -475,6 +885,7 @@ mod tests {
         let mask = FinderMask {
             mask: String::from(""),
             banned: String::from("abcdr"),
+            ..Default::default()
         };
         let words = vec![
             String::from("abra"),
@@ -489,6 +900,7 @@ mod tests {
         let mask = FinderMask {
             mask: String::from(""),
             banned: String::from("wqf"),
+            ..Default::default()
         };
         let words = vec![
             String::from("abra"),
@@ -503,10 +915,12 @@ mod tests {
         let mask1 = FinderMask {
             mask: String::from(""),
             banned: String::from("abc"),
+            ..Default::default()
         };
         let mask2 = FinderMask {
             mask: String::from(""),
             banned: String::from("abcabc"),
+            ..Default::default()
         };
         let words = vec![
             String::from("abra"),
@@ -518,4 +932,201 @@ mod tests {
         assert_eq!(retained1, vec![String::from("poke")]);
         assert_eq!(retained2, retained1);
     }
+
+    fn word(word: &str, frequency: Option<&str>) -> Word {
+        Word {
+            word: word.to_string(),
+            score: None,
+            tags: frequency.map(|freq| vec![format!("f:{}", freq)]),
+        }
+    }
+
+    #[test]
+    fn finder_mask_allows_options() {
+        let input = String::from("a__ow, jfk sort=alpha min_freq=0.5");
+        let output = FinderMask::from(input);
+        assert_eq!(
+            output,
+            Ok(FinderMask {
+                mask: String::from("a__ow"),
+                banned: String::from("jfk"),
+                order: FinderOrder::Alphabetical,
+                min_freq: Some(0.5),
+                hint: None,
+            })
+        );
+    }
+    #[test]
+    fn finder_mask_allows_options_without_banlist() {
+        let input = String::from("a__ow min_freq=3");
+        let output = FinderMask::from(input);
+        assert_eq!(
+            output,
+            Ok(FinderMask {
+                mask: String::from("a__ow"),
+                banned: String::from(""),
+                order: FinderOrder::Frequency,
+                min_freq: Some(3.0),
+                hint: None,
+            })
+        );
+    }
+    #[test]
+    fn finder_mask_disallows_unknown_options() {
+        let input = String::from("a__ow sort=random");
+        let output = FinderMask::from(input);
+        assert_eq!(output, Err(MaskParsingError::WrongFormat));
+
+        let input = String::from("a__ow max=3");
+        let output = FinderMask::from(input);
+        assert_eq!(output, Err(MaskParsingError::WrongFormat));
+    }
+    #[test]
+    fn finder_mask_disallows_non_finite_frequency() {
+        for value in ["nan", "NaN", "inf", "-infinity"] {
+            let input = format!("a__ow min_freq={}", value);
+            let output = FinderMask::from(input);
+            assert_eq!(output, Err(MaskParsingError::WrongFormat), "{}", value);
+        }
+    }
+    #[test]
+    fn finder_mask_ranks_by_frequency_by_default() {
+        let mask = FinderMask::default();
+        let words = vec![
+            word("agley", None),
+            word("ugly", Some("27.4")),
+            word("only", Some("1225.5")),
+        ];
+        let ranked = mask.rank(words);
+        assert_eq!(ranked, vec!["only", "ugly", "agley"]);
+    }
+    #[test]
+    fn finder_mask_ranks_alphabetically() {
+        let mask = FinderMask {
+            order: FinderOrder::Alphabetical,
+            ..Default::default()
+        };
+        let words = vec![word("ugly", Some("27.4")), word("only", Some("1225.5"))];
+        let ranked = mask.rank(words);
+        assert_eq!(ranked, vec!["only", "ugly"]);
+    }
+    #[test]
+    fn finder_mask_ranks_only_frequent_enough() {
+        let mask = FinderMask {
+            min_freq: Some(1.0),
+            ..Default::default()
+        };
+        let words = vec![
+            word("agley", None),
+            word("ugly", Some("27.4")),
+            word("idly", Some("0.9")),
+        ];
+        let ranked = mask.rank(words);
+        assert_eq!(ranked, vec!["ugly"]);
+    }
+
+    #[test]
+    fn finder_mask_allows_wildcards_and_classes() {
+        let input = String::from("un*[ai]bl@, x");
+        let output = FinderMask::from(input);
+        assert_eq!(
+            output,
+            Ok(FinderMask {
+                mask: String::from("un*[ai]bl@"),
+                banned: String::from("x"),
+                ..Default::default()
+            })
+        );
+    }
+    #[test]
+    fn finder_mask_disallows_broken_classes() {
+        for input in ["c[at", "c[]t", "c]at", "c[a[b]]t"] {
+            let output = FinderMask::from(input.to_string());
+            assert_eq!(output, Err(MaskParsingError::WrongFormat), "{}", input);
+        }
+    }
+    #[test]
+    fn finder_mask_counts_length_by_positions() {
+        let output = FinderMask::from(String::from("[abcdefghijklmnop]a"));
+        assert!(output.is_ok());
+
+        let output = FinderMask::from(String::from("*a*"));
+        assert_eq!(output, Err(MaskParsingError::InvalidLength));
+    }
+    #[test]
+    fn finder_mask_disallows_only_wildcards() {
+        let output = FinderMask::from(String::from("#@#@*"));
+        assert_eq!(output, Err(MaskParsingError::InvalidQuery));
+    }
+    #[test]
+    fn finder_mask_translates_to_datamuse() {
+        let mask = FinderMask::from(String::from("un*[ai]bl#_")).unwrap();
+        assert_eq!(mask.datamuse_mask(), "un*_bl__");
+    }
+    #[test]
+    fn finder_mask_retains_only_matching() {
+        let mask = FinderMask::from(String::from("#[ao]*t")).unwrap();
+        let words = vec![
+            String::from("cat"),
+            String::from("coast"),
+            String::from("at"),
+            String::from("cut"),
+            String::from("hot pot"),
+        ];
+        let retained = mask.retain_only_matching(words);
+        assert_eq!(retained, vec![String::from("cat"), String::from("coast")]);
+    }
+
+    #[test]
+    fn finder_mask_ignores_accents() {
+        let mask = FinderMask::from(String::from("caf_, é")).unwrap();
+        assert_eq!(mask.mask, "caf_");
+        assert_eq!(mask.banned, "e");
+        let words = vec![String::from("café"), String::from("cafa")];
+        assert_eq!(
+            mask.retain_only_matching(words.clone()),
+            vec![String::from("café"), String::from("cafa")]
+        );
+        assert_eq!(mask.retain_only_allowed(words), vec![String::from("cafa")]);
+    }
+
+    #[test]
+    fn finder_mask_allows_clue() {
+        let input = String::from("c_t, feline pet");
+        let output = FinderMask::with_hint(input);
+        assert_eq!(
+            output,
+            Ok(FinderMask {
+                mask: String::from("c_t"),
+                hint: Some(String::from("feline pet")),
+                ..Default::default()
+            })
+        );
+    }
+    #[test]
+    fn finder_mask_allows_clue_without_known_letters() {
+        let input = String::from("___, feline pet ");
+        let output = FinderMask::with_hint(input);
+        assert_eq!(
+            output,
+            Ok(FinderMask {
+                mask: String::from("___"),
+                hint: Some(String::from("feline pet")),
+                ..Default::default()
+            })
+        );
+    }
+    #[test]
+    fn finder_mask_disallows_clue_without_hint() {
+        let output = FinderMask::with_hint(String::from("c_t"));
+        assert_eq!(output, Err(MaskParsingError::WrongFormat));
+
+        let output = FinderMask::with_hint(String::from("c_t, "));
+        assert_eq!(output, Err(MaskParsingError::WrongFormat));
+    }
+    #[test]
+    fn finder_mask_disallows_clue_without_wildcards() {
+        let output = FinderMask::with_hint(String::from("cat, feline pet"));
+        assert_eq!(output, Err(MaskParsingError::InvalidQuery));
+    }
 }
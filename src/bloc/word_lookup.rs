@@ -1,14 +1,85 @@
 use crate::bloc::common::{CommandHandler, LookupError};
 use crate::bot::{LookupBot, LookupBotX};
+use crate::callbacks::{
+    ResultSource, origin_keyboard, sources_keyboard, with_sources_row, word_lookup_keyboard,
+};
+use crate::datamuse::client::DatamuseClient;
+use crate::datamuse::request::SuggestWordsRequest;
 use crate::format::LookupFormatter;
+use crate::lemma::base_forms;
+use crate::pronunciation::PronunciationClient;
+use crate::pronunciation::requests::PronunciationRequest;
 use crate::stands4::requests::{SearchAbbrsRequest, SearchWordRequest};
 use crate::stands4::{
-    AbbreviationDefinition, SliceAbbreviationsExt, Stands4Client, WordDefinition,
+    AbbreviationDefinition, PartOfSpeech, SliceAbbreviationsExt, SliceWordsExt, Stands4Client,
+    WordDefinition,
 };
-use futures::TryFutureExt;
+use futures::{FutureExt, TryFutureExt};
+use std::time::Duration;
 use teloxide::dptree::entry;
 
-type Entity = (Vec<WordDefinition>, Vec<AbbreviationDefinition>);
+/// How long the lookup waits for the optional IPA transcription, so that a slow pronunciation API
+/// doesn't hold the definitions back.
+const IPA_DEADLINE: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, Default)]
+pub struct Entity {
+    words: Vec<WordDefinition>,
+    abbrs: Vec<AbbreviationDefinition>,
+    /// The base form `words` were found for, when the looked up word itself yielded nothing.
+    lemma: Option<String>,
+    /// The part of speech `words` were narrowed down to.
+    pos: Option<PartOfSpeech>,
+    /// IPA transcription of the looked up word.
+    ipa: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WordQuery {
+    word: String,
+    pos: Option<PartOfSpeech>,
+}
+
+impl WordQuery {
+    /// Parses a word lookup query, optionally narrowed down to a part of speech.
+    ///
+    /// The part of speech may follow the word after a colon, like `run:v` or `run: verb`, or after a space,
+    /// like `run verb`. Since a trailing single letter may as well belong to the looked up phrase,
+    /// e.g. `vitamin a`, single-letter abbreviations are only recognized after a colon.
+    /// Anything else is looked up as it is, colons included, like `re:x` or `c:drive`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let query = WordQuery::parse("run:v");
+    /// assert_eq!(query.pos, Some(PartOfSpeech::Verb));
+    /// assert_eq!(WordQuery::parse("vitamin a").pos, None);
+    /// assert_eq!(WordQuery::parse("run:xyz").word, "run:xyz");
+    /// ```
+    pub fn parse(query: &str) -> Self {
+        let query = query.trim();
+        let narrowed = match query.split_once(':') {
+            Some((word, pos)) => PartOfSpeech::parse(pos).map(|pos| (word.trim(), pos)),
+            None => query
+                .rsplit_once(char::is_whitespace)
+                .filter(|(_, pos)| pos.len() > 1)
+                .and_then(|(word, pos)| Some((word.trim(), PartOfSpeech::parse(pos)?))),
+        };
+        match narrowed.filter(|(word, _)| !word.is_empty()) {
+            Some((word, pos)) => WordQuery {
+                word: word.to_string(),
+                pos: Some(pos),
+            },
+            None => WordQuery {
+                word: query.to_string(),
+                pos: None,
+            },
+        }
+    }
+}
+
+/// How many spelling suggestions are offered when nothing was found.
+const MAX_SUGGESTIONS: usize = 3;
 
 pub trait WordLookupBot<Response>
 where
@@ -34,45 +105,170 @@ where
 }
 
 pub trait WordLookupHandler {
-    /// Perform concurrent lookups for both word definitions and abbreviation definitions.
+    /// Perform concurrent lookups for word definitions, abbreviation definitions and the word's pronunciation.
     ///
-    /// If either lookup fails, the error is logged and an empty vector is substituted for that result.
+    /// If either lookup fails, the error is logged and an empty vector (or no pronunciation) is substituted for that result.
+    /// The pronunciation is optional, so it's given up on if it takes longer than `IPA_DEADLINE`.
+    /// When the query is narrowed down to a part of speech, only the word definitions of that part of speech
+    /// are kept, while abbreviations aren't looked up at all.
+    /// When no word definitions are found, the word may be an inflected form, so the lookup is retried
+    /// with each of its guessed base forms (see [`base_forms`]) until one of them yields definitions.
     ///
-    /// Returns a tuple `(words, abbrs)` where `words` is a `Vec<WordDefinition>` from the word lookup
-    /// and `abbrs` is a `Vec<AbbreviationDefinition>` from the abbreviation lookup.
+    /// Returns an `Entity` with the word definitions, the abbreviation definitions, the IPA transcription
+    /// and, if the words were found for a base form, that base form.
     ///
     /// # Examples
     ///
     /// ```ignore
     /// // `client` must implement the `Stands4Client` API used here.
-    /// # async fn example(client: Stands4Client) {
-    /// let (words, abbrs) = get_definitions(client, "rust".to_string()).await;
-    /// // `words` is Vec<WordDefinition>, `abbrs` is Vec<AbbreviationDefinition>.
+    /// # async fn example(client: Stands4Client, pronunciations: PronunciationClient) {
+    /// let entity = get_definitions(client, pronunciations, WordQuery::parse("running")).await;
+    /// // `entity.words` are the definitions of "run", and `entity.lemma` is `Some("run")`.
     /// # }
     /// ```
-    async fn get_definitions(client: Stands4Client, word: String) -> Entity {
-        futures::future::join(
-            client
-                .exec(SearchWordRequest { word: word.clone() })
-                .unwrap_or_else(|err| {
-                    log::error!("Failed to retrieve definitions of a word: {:?}", err);
-                    vec![]
-                }),
-            client
-                .exec(SearchAbbrsRequest { term: word.clone() })
-                .unwrap_or_else(|err| {
-                    log::error!("Failed to retrieve definitions of an abbr: {:?}", err);
-                    vec![]
-                }),
-        )
-        .await
+    async fn get_definitions(
+        client: Stands4Client,
+        pronunciations: PronunciationClient,
+        query: WordQuery,
+    ) -> Entity {
+        let WordQuery { word, pos } = query;
+        let abbrs = async {
+            match pos {
+                Some(_) => vec![],
+                None => {
+                    client
+                        .exec(SearchAbbrsRequest { term: word.clone() })
+                        .unwrap_or_else(|err| {
+                            log::error!("Failed to retrieve definitions of an abbr: {:?}", err);
+                            vec![]
+                        })
+                        .await
+                }
+            }
+        };
+        let ipa = pronunciations
+            .exec(PronunciationRequest::new(word.clone()))
+            .map_ok(|pronunciation| pronunciation.and_then(|it| it.ipa))
+            .unwrap_or_else(|err| {
+                log::error!("Failed to retrieve pronunciation of a word: {:?}", err);
+                None
+            });
+        let ipa = tokio::time::timeout(IPA_DEADLINE, ipa).map(|ipa| {
+            ipa.unwrap_or_else(|_| {
+                log::warn!(
+                    "Gave up on the pronunciation of a word after {:?}",
+                    IPA_DEADLINE
+                );
+                None
+            })
+        });
+        let (words, abbrs, ipa) =
+            futures::future::join3(search_words(&client, word.clone(), pos), abbrs, ipa).await;
+        if words.is_empty() {
+            for lemma in base_forms(&word) {
+                let words = search_words(&client, lemma.clone(), pos).await;
+                if !words.is_empty() {
+                    return Entity {
+                        words,
+                        abbrs,
+                        lemma: Some(lemma),
+                        pos,
+                        ipa,
+                    };
+                }
+            }
+        }
+        Entity {
+            words,
+            abbrs,
+            lemma: None,
+            pos,
+            ipa,
+        }
+    }
+
+    /// Tidy up the found word definitions before they are formatted.
+    ///
+    /// Near-duplicates are collapsed and the definitions are grouped by part of speech,
+    /// see [`SliceWordsExt::organized`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let entity = organize_definitions(entity);
+    /// ```
+    fn organize_definitions(entity: Entity) -> Entity {
+        Entity {
+            words: entity.words.organized(),
+            ..entity
+        }
+    }
+
+    /// Ask Datamuse for correctly spelled words close to the one that yielded no results.
+    ///
+    /// The word itself is never suggested back and at most `MAX_SUGGESTIONS` words are kept, best first.
+    /// If the request fails, the error is logged and no suggestions are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example(client: DatamuseClient) {
+    /// let suggestions = get_spelling_suggestions(client, WordQuery::parse("recieve")).await;
+    /// // e.g. ["receive", "relieve"]
+    /// # }
+    /// ```
+    async fn get_spelling_suggestions(client: DatamuseClient, query: WordQuery) -> Vec<String> {
+        let word = query.word;
+        client
+            .exec_ranked(SuggestWordsRequest::new(word.clone()))
+            .await
+            .map(|words| {
+                words
+                    .into_iter()
+                    .filter(|suggestion| !suggestion.eq_ignore_ascii_case(&word))
+                    .take(MAX_SUGGESTIONS)
+                    .collect()
+            })
+            .unwrap_or_else(|err| {
+                log::error!("Failed to retrieve spelling suggestions: {:?}", err);
+                vec![]
+            })
     }
 
     fn word_lookup_handler() -> CommandHandler;
 }
 
+/// Search Stands4 for the definitions of a word, logging a failure and substituting an empty vector for it.
+///
+/// If `pos` is given, only the definitions of that part of speech are kept.
+async fn search_words(
+    client: &Stands4Client,
+    word: String,
+    pos: Option<PartOfSpeech>,
+) -> Vec<WordDefinition> {
+    let words: Vec<WordDefinition> = client
+        .exec(SearchWordRequest { word })
+        .await
+        .unwrap_or_else(|err| {
+            log::error!("Failed to retrieve definitions of a word: {:?}", err);
+            vec![]
+        });
+    match pos {
+        Some(pos) => words
+            .into_iter()
+            .filter(|def| def.pos() == Some(pos))
+            .collect(),
+        None => words,
+    }
+}
+
 pub trait WordLookupFormatter<Value, Error> {
-    fn compose_word_defs(self, word: &str, defs: &[WordDefinition]) -> Result<Value, Error>;
+    fn compose_word_defs(
+        self,
+        word: &str,
+        defs: &[WordDefinition],
+        pos: Option<PartOfSpeech>,
+    ) -> Result<Value, Error>;
 
     fn compose_abbr_defs(self, word: &str, defs: &[AbbreviationDefinition])
     -> Result<Value, Error>;
@@ -85,15 +281,22 @@ pub trait WordLookupFormatter<Value, Error> {
     ) -> Result<Value, Error>;
 
     fn compose_word_response(self, word: String, entity: Entity) -> Result<Value, LookupError>;
+
+    fn compose_spelling_suggestions(
+        self,
+        word: &str,
+        suggestions: &[String],
+    ) -> Result<Value, LookupError>;
 }
 
 impl<Formatter> WordLookupFormatter<Formatter::Value, Formatter::Error> for Formatter
 where
     Formatter: LookupFormatter,
 {
-    /// Appends a title and up to five word definitions to the formatter, adding a link if more exist.
+    /// Appends a title and up to the chat's `max_results` word definitions to the formatter, adding a link if more exist.
     ///
-    /// Adds a heading "Found N definitions", visits up to five definitions with `visit_word`, appends a link to the word when more than five definitions are present, then builds and returns the formatter's value.
+    /// Adds a heading "Found N definitions", or "Found N verb definitions" if the definitions were narrowed
+    /// down to a part of speech, visits up to the chat's `max_results` definitions with `visit_word`, appends a link to the word when more definitions are present, then builds and returns the formatter's value.
     ///
     /// # Returns
     ///
@@ -103,19 +306,26 @@ where
     ///
     /// ```
     /// // Assuming a type `F` implements the required `LookupFormatter` methods:
-    /// // let result = F::new().compose_word_defs("example", &definitions)?;
+    /// // let result = F::new().compose_word_defs("example", &definitions, None)?;
     /// ```
     fn compose_word_defs(
         mut self,
         word: &str,
         defs: &[WordDefinition],
+        pos: Option<PartOfSpeech>,
     ) -> Result<Formatter::Value, Formatter::Error> {
-        self.append_title(format!("Found {} definitions", defs.len()));
+        match pos {
+            Some(pos) => {
+                self.append_title(format!("Found {} {} definitions", defs.len(), pos.name()))
+            }
+            None => self.append_title(format!("Found {} definitions", defs.len())),
+        }
 
-        for (i, def) in defs.iter().take(5).enumerate() {
+        let max = self.max_results();
+        for (i, def) in defs.iter().take(max).enumerate() {
             self.visit_word(i, def);
         }
-        if defs.len() > 5 {
+        if defs.len() > max {
             self.append_link(self.link_provider().word_link(word))
         }
         self.build()
@@ -123,7 +333,7 @@ where
 
     /// Formats abbreviation definitions for a word by categorizing them and composing the formatter's output.
     ///
-    /// The formatter will include up to five categories of abbreviations and, if more categories exist,
+    /// The formatter will include up to the chat's `max_results` categories of abbreviations and, if more categories exist,
     /// append a link to the full abbreviation list for the given word.
     ///
     /// # Parameters
@@ -152,11 +362,12 @@ where
     ) -> Result<Formatter::Value, Formatter::Error> {
         self.append_title(format!("Found {} definitions", defs.len()));
 
+        let max = self.max_results();
         let categorized = defs.categorized();
-        for (i, (category, defs)) in categorized.iter().take(5).enumerate() {
+        for (i, (category, defs)) in categorized.iter().take(max).enumerate() {
             self.visit_abbreviations(i, category, defs);
         }
-        if categorized.len() > 5 {
+        if categorized.len() > max {
             self.append_link(self.link_provider().abbr_link(word))
         }
         self.build()
@@ -164,8 +375,8 @@ where
 
     /// Compose a formatted response containing both word definitions and categorized abbreviations for a query.
     ///
-    /// This appends a title for word definitions, visits up to five word definitions (adding a "more" link if there are more),
-    /// then appends a title for abbreviations, visits up to five abbreviation categories (adding an abbreviation "more" link if there are more),
+    /// This appends a title for word definitions, visits up to the chat's `max_results` word definitions (adding a "more" link if there are more),
+    /// then appends a title for abbreviations, visits up to `max_results` abbreviation categories (adding an abbreviation "more" link if there are more),
     /// and finally builds the formatter's output.
     ///
     /// Parameters:
@@ -200,20 +411,21 @@ where
     ) -> Result<Formatter::Value, Formatter::Error> {
         self.append_title(format!("Found {} definitions", words.len()));
 
-        for (i, def) in words.iter().take(5).enumerate() {
+        let max = self.max_results();
+        for (i, def) in words.iter().take(max).enumerate() {
             self.visit_word(i, def);
         }
-        if words.len() > 5 {
+        if words.len() > max {
             self.append_link(self.link_provider().word_link(word))
         }
 
         self.append_title(format!("Found {} abbreviations", abbrs.len()));
 
         let categorized = abbrs.categorized();
-        for (i, (category, defs)) in categorized.iter().take(5).enumerate() {
+        for (i, (category, defs)) in categorized.iter().take(max).enumerate() {
             self.visit_abbreviations(i, category, defs);
         }
-        if categorized.len() > 5 {
+        if categorized.len() > max {
             self.append_link(self.link_provider().abbr_link(word))
         }
 
@@ -223,9 +435,12 @@ where
     /// Selects and builds the appropriate formatted response for a lookup result containing
     /// word definitions and abbreviations.
     ///
-    /// This method dispatches to one of the formatter's composing helpers based on which parts
-    /// of the composite `Entity` are present:
-    /// - If both word definitions and abbreviations are empty, returns `Self::on_empty()`.
+    /// The word's pronunciation is visited first, if known, followed by a note if the words were found
+    /// for a base form of the queried word.
+    /// Then this method dispatches to one of the formatter's composing helpers based on which parts
+    /// of the `Entity` are present:
+    /// - If both word definitions and abbreviations are empty, returns `Self::on_empty()`, unless the
+    ///   definitions were narrowed down to a part of speech, which is then reflected in the title.
     /// - If only abbreviations are present, delegates to `compose_abbr_defs`.
     /// - If only word definitions are present, delegates to `compose_word_defs`.
     /// - If both are present, delegates to `compose_words_with_abbrs`.
@@ -242,19 +457,33 @@ where
     ///
     /// ```no_run
     /// // Assume `formatter` implements the trait providing this method.
-    /// // The call picks the appropriate composition path from the tuple contents.
-    /// let entity_empty: (Vec<_>, Vec<_>) = (Vec::new(), Vec::new());
+    /// // The call picks the appropriate composition path from the entity contents.
+    /// let entity_empty = Entity::default();
     /// // let result = formatter.compose_word_response("term".to_string(), entity_empty);
     /// ```
     fn compose_word_response(
-        self,
+        mut self,
         word: String,
-        (words, abbrs): Entity,
+        entity: Entity,
     ) -> Result<Formatter::Value, LookupError> {
+        let Entity {
+            words,
+            abbrs,
+            lemma,
+            pos,
+            ipa,
+        } = entity;
+        if let Some(ipa) = &ipa {
+            self.visit_pronunciation(&word, ipa);
+        }
+        if let Some(lemma) = &lemma {
+            self.visit_lemma(lemma, &word);
+        }
         let text = match (words.len(), abbrs.len()) {
-            (0, 0) => Ok(Self::on_empty()),
+            (0, 0) if pos.is_none() => Ok(Self::on_empty()),
+            (0, 0) => self.compose_word_defs(&word, &words, pos),
             (0, _) => self.compose_abbr_defs(&word, &abbrs),
-            (_, 0) => self.compose_word_defs(&word, &words),
+            (_, 0) => self.compose_word_defs(&word, &words, pos),
             (_, _) => self.compose_words_with_abbrs(&word, &words, &abbrs),
         };
         text.map_err(|err| {
@@ -262,6 +491,30 @@ where
             LookupError::FailedResponseBuilder
         })
     }
+
+    /// Compose a "did you mean" response for a word that yielded no definitions.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(Value)` containing the composed response, or `Err(LookupError::FailedResponseBuilder)`
+    /// when the response builder fails.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let response = formatter.compose_spelling_suggestions("recieve", &suggestions)?;
+    /// ```
+    fn compose_spelling_suggestions(
+        mut self,
+        word: &str,
+        suggestions: &[String],
+    ) -> Result<Formatter::Value, LookupError> {
+        self.visit_spelling_suggestions(word, suggestions);
+        self.build().map_err(|err| {
+            log::error!("Failed to construct a response: {:?}", err);
+            LookupError::FailedResponseBuilder
+        })
+    }
 }
 
 impl<Bot, Formatter> WordLookupHandler for Bot
@@ -271,8 +524,13 @@ where
 {
     /// Builds a teloxide dptree CommandHandler that handles a phrase lookup and sends the bot's formatted response.
     ///
-    /// The handler chain drops empty phrases (using `Bot::on_empty`), retrieves word and abbreviation definitions,
+    /// The handler chain drops empty phrases (using `Bot::on_empty`), parses an optional part of speech,
+    /// retrieves word and abbreviation definitions,
+    /// collapses near-duplicate word definitions and groups them by part of speech,
     /// composes a formatted response or substitutes a generic error response, and sends the result via the bot.
+    /// When neither words nor abbreviations are found for an unnarrowed query, the closest correctly spelled words are offered instead,
+    /// each with a button that looks it up. Found words come with an "Origin" button that shows the etymology of the word, or of its lemma.
+    /// Under the result, a row of buttons switches it to another source.
     ///
     /// # Examples
     ///
@@ -285,9 +543,35 @@ where
             .filter_async(|bot: Bot, phrase: String| async move {
                 bot.drop_empty(phrase, Bot::on_empty).await
             })
+            .map(|phrase: String| WordQuery::parse(&phrase))
             .map_async(Self::get_definitions)
-            .map(move |bot: Bot, phrase: String, defs: Entity| {
-                bot.formatter().compose_word_response(phrase, defs)
+            .map(Self::organize_definitions)
+            .branch(
+                teloxide::dptree::filter(|entity: Entity| {
+                    entity.words.is_empty() && entity.abbrs.is_empty() && entity.pos.is_none()
+                })
+                .map_async(Self::get_spelling_suggestions)
+                .filter(|suggestions: Vec<String>| !suggestions.is_empty())
+                .map(
+                    move |bot: Bot, query: WordQuery, suggestions: Vec<String>| {
+                        bot.formatter()
+                            .compose_spelling_suggestions(&query.word, &suggestions)
+                    },
+                )
+                .filter_map_async(
+                    |bot: Bot, response: Result<Bot::Response, LookupError>| async move {
+                        bot.retrieve_or_generic_err(response).await
+                    },
+                )
+                .endpoint(
+                    |bot: Bot, suggestions: Vec<String>, response: Bot::Response| async move {
+                        let keyboard = word_lookup_keyboard(&suggestions);
+                        bot.respond_with_keyboard(response, keyboard).await
+                    },
+                ),
+            )
+            .map(move |bot: Bot, query: WordQuery, defs: Entity| {
+                bot.formatter().compose_word_response(query.word, defs)
             })
             .filter_map_async(
                 |bot: Bot, response: Result<Bot::Response, LookupError>| async move {
@@ -295,7 +579,90 @@ where
                 },
             )
             .endpoint(
-                |bot: Bot, response: Bot::Response| async move { bot.respond(response).await },
+                |bot: Bot, query: WordQuery, entity: Entity, response: Bot::Response| async move {
+                    let source = ResultSource::Dictionary;
+                    let keyboard = match entity.words.is_empty() {
+                        true => sources_keyboard(&query.word, source, bot.settings()),
+                        false => {
+                            let origin = entity.lemma.as_deref().unwrap_or(&query.word);
+                            let keyboard = origin_keyboard(origin);
+                            with_sources_row(keyboard, &query.word, source, bot.settings())
+                        }
+                    };
+                    bot.respond_with_keyboard(response, keyboard).await
+                },
             )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::FullMessageFormatter;
+
+    #[test]
+    fn lemma_note_precedes_definitions() {
+        let entity = Entity {
+            words: vec![WordDefinition {
+                term: "run".to_string(),
+                definition: "move fast by using one's feet".to_string(),
+                example: String::new(),
+                part_of_speech: "verb".to_string(),
+            }],
+            abbrs: vec![],
+            lemma: Some("run".to_string()),
+            pos: None,
+            ipa: None,
+        };
+        let response = FullMessageFormatter::default()
+            .compose_word_response("running".to_string(), entity)
+            .unwrap();
+        assert!(response.starts_with(
+            "Showing results for *run* \\(from *running*\\)\n\nFound 1 definitions\n\n"
+        ));
+    }
+
+    #[test]
+    fn query_parses_pos_after_colon() {
+        let query = WordQuery::parse("run:v");
+        assert_eq!(query.word, "run");
+        assert_eq!(query.pos, Some(PartOfSpeech::Verb));
+        let query = WordQuery::parse("run : Noun");
+        assert_eq!(query.word, "run");
+        assert_eq!(query.pos, Some(PartOfSpeech::Noun));
+    }
+
+    #[test]
+    fn query_parses_pos_after_space() {
+        let query = WordQuery::parse("run verb");
+        assert_eq!(query.word, "run");
+        assert_eq!(query.pos, Some(PartOfSpeech::Verb));
+        let query = WordQuery::parse("vitamin a");
+        assert_eq!(query.word, "vitamin a");
+        assert_eq!(query.pos, None);
+        let query = WordQuery::parse("run");
+        assert_eq!(query.word, "run");
+        assert_eq!(query.pos, None);
+    }
+
+    #[test]
+    fn unknown_pos_after_colon_is_looked_up_as_is() {
+        for text in ["run:xyz", "re:x", "c:drive", "http://example.com", ":v"] {
+            let query = WordQuery::parse(text);
+            assert_eq!(query.word, text);
+            assert_eq!(query.pos, None);
+        }
+    }
+
+    #[test]
+    fn pos_filter_is_reflected_in_title() {
+        let entity = Entity {
+            pos: Some(PartOfSpeech::Verb),
+            ..Default::default()
+        };
+        let response = FullMessageFormatter::default()
+            .compose_word_response("run".to_string(), entity)
+            .unwrap();
+        assert_eq!(response, "Found 0 verb definitions\n\n");
+    }
+}
@@ -80,11 +80,12 @@ where
             ..
         }: WordleDayAnswer,
     ) -> Result<Formatter::Value, LookupError> {
-        self.append_title(format!(
-            "Today's answer: `{}`",
-            answer.solution.to_uppercase()
-        ));
-        self.compose_word_defs(&answer.solution, &definitions)
+        let solution = answer.solution.to_uppercase();
+        match self.settings().wordle_spoiler {
+            true => self.append_title(format!("Today's answer: ||`{}`||", solution)),
+            false => self.append_title(format!("Today's answer: `{}`", solution)),
+        }
+        self.compose_word_defs(&answer.solution, &definitions, None)
             .map_err(|err| {
                 log::error!("Failed to build wordle response {:?}", err);
                 LookupError::FailedResponseBuilder
@@ -0,0 +1,214 @@
+use crate::bloc::abbr_lookup::AbbrLookupBot;
+use crate::bloc::etymology::EtymologyBot;
+use crate::bloc::phrase_lookup::PhraseLookupBot;
+use crate::bloc::pronunciation::PronunciationBot;
+use crate::bloc::settings::{SettingsBot, may_change_settings};
+use crate::bloc::thesaurus_lookup::ThesaurusLookupBot;
+use crate::bloc::urban_lookup::UrbanLookupBot;
+use crate::bloc::word_lookup::WordLookupBot;
+use crate::bot::{LookupBot, MessageBot};
+use crate::commands::FullMessageFormatter;
+use crate::format::ToEscaped;
+use crate::settings::ChatSettings;
+use teloxide::Bot;
+use teloxide::payloads::{EditMessageTextInlineSetters, EditMessageTextSetters, SendAudioSetters};
+use teloxide::prelude::Requester;
+use teloxide::types::{
+    CallbackQuery, ChatId, InlineKeyboardMarkup, InputFile, ParseMode, ReplyParameters,
+};
+
+#[derive(Debug, Clone)]
+pub struct CallbackBot {
+    pub bot: Bot,
+    pub query: CallbackQuery,
+    pub settings: ChatSettings,
+}
+
+impl CallbackBot {
+    /// The chat the pressed button belongs to.
+    ///
+    /// A message sent via inline mode isn't known to belong to any chat,
+    /// so the private chat with the user who pressed the button is assumed then.
+    pub fn chat_id(query: &CallbackQuery) -> ChatId {
+        match &query.message {
+            Some(message) => message.chat().id,
+            None => query.from.id.into(),
+        }
+    }
+
+    /// Replaces the text of the message the button belongs to.
+    ///
+    /// Both regular chat messages and messages sent via inline mode are edited, the latter being
+    /// addressed by their `inline_message_id`. The text is parsed as MarkdownV2.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the request fails or if the message is not known to the query.
+    async fn edit(
+        &self,
+        text: String,
+        keyboard: Option<InlineKeyboardMarkup>,
+    ) -> anyhow::Result<()> {
+        match (&self.query.message, &self.query.inline_message_id) {
+            (Some(message), _) => {
+                let mut request = self
+                    .bot
+                    .edit_message_text(message.chat().id, message.id(), text)
+                    .parse_mode(ParseMode::MarkdownV2);
+                if let Some(keyboard) = keyboard {
+                    request = request.reply_markup(keyboard);
+                }
+                let _ = request.await?;
+            }
+            (None, Some(inline_message_id)) => {
+                let mut request = self
+                    .bot
+                    .edit_message_text_inline(inline_message_id, text)
+                    .parse_mode(ParseMode::MarkdownV2);
+                if let Some(keyboard) = keyboard {
+                    request = request.reply_markup(keyboard);
+                }
+                let _ = request.await?;
+            }
+            (None, None) => anyhow::bail!("Callback query has no message to edit"),
+        }
+        Ok(())
+    }
+}
+
+impl LookupBot for CallbackBot {
+    type Request = CallbackQuery;
+    type Formatter = FullMessageFormatter;
+    type Response = String;
+
+    fn settings(&self) -> &ChatSettings {
+        &self.settings
+    }
+
+    /// Produces a short, polite error message to present when a query cannot be processed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let msg = CallbackBot::error_response();
+    /// assert!(msg.contains("error processing your query"));
+    /// ```
+    fn error_response() -> Self::Response {
+        "There was an error processing your query, try again later, sorry."
+            .to_string()
+            .to_escaped()
+    }
+
+    /// Replaces the message whose button was pressed with the given MarkdownV2 text.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example_usage(bot: &crate::bot::CallbackBot) -> anyhow::Result<()> {
+    /// bot.answer("Hello, world!".to_string()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn answer(&self, text: String) -> anyhow::Result<()> {
+        self.edit(text, None).await
+    }
+
+    /// Replaces the message whose button was pressed with the given MarkdownV2 text and keyboard.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example_usage(bot: &crate::bot::CallbackBot) -> anyhow::Result<()> {
+    /// bot.answer_with_keyboard("Pick one".to_string(), InlineKeyboardMarkup::default()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn answer_with_keyboard(
+        &self,
+        text: String,
+        keyboard: InlineKeyboardMarkup,
+    ) -> anyhow::Result<()> {
+        self.edit(text, Some(keyboard)).await
+    }
+}
+
+impl WordLookupBot<String> for CallbackBot {}
+impl PhraseLookupBot<String> for CallbackBot {}
+impl ThesaurusLookupBot<String> for CallbackBot {}
+
+impl UrbanLookupBot<String> for CallbackBot {
+    /// Message shown in place of the result when Urban Dictionary got switched off after the buttons were sent.
+    fn on_disabled() -> String {
+        <MessageBot as UrbanLookupBot<String>>::on_disabled()
+    }
+}
+
+impl SettingsBot<String> for CallbackBot {
+    /// The chat the settings menu was sent to, see [`CallbackBot::chat_id`].
+    fn chat(&self) -> ChatId {
+        CallbackBot::chat_id(&self.query)
+    }
+
+    /// Whether the user who pressed the button may change the chat's settings, see [`may_change_settings`].
+    async fn may_change_settings(&self) -> bool {
+        may_change_settings(&self.bot, self.chat(), Some(self.query.from.id)).await
+    }
+
+    /// Text going along the settings menu, kept the same as the one sent by `/settings`.
+    fn settings_response() -> String {
+        <MessageBot as SettingsBot<String>>::settings_response()
+    }
+
+    /// Text shown in place of the menu's when a non-admin presses a button, the same as `/autolookup` replies with.
+    fn on_forbidden() -> String {
+        <MessageBot as SettingsBot<String>>::on_forbidden()
+    }
+}
+
+impl AbbrLookupBot<String> for CallbackBot {}
+
+impl EtymologyBot<String> for CallbackBot {
+    /// Message shown in place of the definitions when Wiktionary knows nothing about the word's origin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let msg = <CallbackBot as EtymologyBot<String>>::on_unknown();
+    /// assert!(msg.contains("origin"));
+    /// ```
+    fn on_unknown() -> String {
+        "Sorry, I don't know the origin of that word."
+            .to_string()
+            .to_escaped()
+    }
+}
+
+impl PronunciationBot<String> for CallbackBot {
+    /// Message shown in place of the picked words when neither a recording nor a transcription is known,
+    /// the same as `/say` replies with.
+    fn on_unknown() -> String {
+        <MessageBot as PronunciationBot<String>>::on_unknown()
+    }
+
+    /// Sends the recording of the picked word as an audio message, in reply to the message with the buttons
+    /// so that more words can be picked from it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the recording URL is malformed or sending the audio fails.
+    async fn send_audio(&self, word: &str, audio: &str, ipa: Option<&str>) -> anyhow::Result<()> {
+        let caption = match ipa {
+            Some(ipa) => format!("{} /{}/", word, ipa),
+            None => word.to_string(),
+        };
+        let mut request = self
+            .bot
+            .send_audio(Self::chat_id(&self.query), InputFile::url(audio.parse()?))
+            .caption(caption);
+        if let Some(message) = &self.query.message {
+            request = request.reply_parameters(ReplyParameters::new(message.id()));
+        }
+        request.await?;
+        Ok(())
+    }
+}
@@ -1,4 +1,7 @@
+use crate::bloc::abbr_lookup::AbbrLookupBot;
+use crate::bloc::all_lookup::AllLookupBot;
 use crate::bloc::phrase_lookup::PhraseLookupBot;
+use crate::bloc::quote_lookup::QuoteLookupBot;
 use crate::bloc::suggestions::SuggestionsBot;
 use crate::bloc::thesaurus_lookup::ThesaurusLookupBot;
 use crate::bloc::urban_lookup::UrbanLookupBot;
@@ -6,14 +9,33 @@ use crate::bloc::word_finder::WordFinderBot;
 use crate::bloc::word_lookup::WordLookupBot;
 use crate::bot::LookupBot;
 use crate::inlines::formatting::InlineFormatter;
+use crate::inlines::{InlinePages, page_of};
+use crate::settings::ChatSettings;
+use teloxide::payloads::AnswerInlineQuerySetters;
 use teloxide::prelude::{InlineQuery, Requester};
-use teloxide::types::InlineQueryResult;
+use teloxide::types::{InlineKeyboardMarkup, InlineQueryResult};
 use teloxide::Bot;
 
 #[derive(Debug, Clone)]
 pub struct InlineBot {
     pub bot: Bot,
     pub query: InlineQuery,
+    pub settings: ChatSettings,
+    pub pages: InlinePages,
+}
+
+impl InlineBot {
+    /// The results kept from the first page of the query, if the query asks for one of the next pages.
+    ///
+    /// # Returns
+    ///
+    /// `Some` results to take the page from, or `None` if the query has to be looked up.
+    pub fn kept_results(&self) -> Option<Vec<InlineQueryResult>> {
+        if self.query.offset.is_empty() {
+            return None;
+        }
+        self.pages.get(self.query.from.id, &self.query.query)
+    }
 }
 
 impl LookupBot for InlineBot {
@@ -21,9 +43,14 @@ impl LookupBot for InlineBot {
     type Formatter = InlineFormatter;
     type Response = Vec<InlineQueryResult>;
 
+    fn settings(&self) -> &ChatSettings {
+        &self.settings
+    }
+
     /// Sends the stored inline query's answers to Telegram.
     ///
-    /// Sends the provided `answers` as the response to the `InlineQuery` contained in this bot.
+    /// Sends the page of the provided `answers` the `InlineQuery` contained in this bot asks for,
+    /// pointing Telegram to the next page if there is one. The answers are kept for the next pages.
     /// Returns `Ok(())` on success, or an error containing the underlying API failure.
     ///
     /// # Examples
@@ -35,16 +62,104 @@ impl LookupBot for InlineBot {
     /// # }
     /// ```
     async fn answer(&self, answers: Vec<InlineQueryResult>) -> anyhow::Result<()> {
-        let query_id = self.query.id.clone();
-        let _ = self.bot.answer_inline_query(query_id, answers).await?;
+        let InlineQuery {
+            id,
+            from,
+            query,
+            offset,
+            ..
+        } = &self.query;
+        self.pages.insert(from.id, query, &answers);
+        let (page, next_offset) = page_of(answers, offset);
+        let _ = self
+            .bot
+            .answer_inline_query(id.clone(), page)
+            .next_offset(next_offset)
+            .await?;
         Ok(())
     }
+
+    /// Sends the inline query's answers with the keyboard attached to each of the articles.
+    ///
+    /// The keyboard shows up under the message once the user picks one of the answers.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run_example(bot: &crate::InlineBot) -> anyhow::Result<()> {
+    /// bot.answer_with_keyboard(vec![], InlineKeyboardMarkup::default()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn answer_with_keyboard(
+        &self,
+        answers: Vec<InlineQueryResult>,
+        keyboard: InlineKeyboardMarkup,
+    ) -> anyhow::Result<()> {
+        let answers = answers
+            .into_iter()
+            .map(|answer| match answer {
+                InlineQueryResult::Article(article) => {
+                    InlineQueryResult::Article(article.reply_markup(keyboard.clone()))
+                }
+                other => other,
+            })
+            .collect();
+        self.answer(answers).await
+    }
 }
 
 impl WordLookupBot<Vec<InlineQueryResult>> for InlineBot {}
 impl PhraseLookupBot<Vec<InlineQueryResult>> for InlineBot {}
+impl AbbrLookupBot<Vec<InlineQueryResult>> for InlineBot {}
+impl AllLookupBot<Vec<InlineQueryResult>> for InlineBot {}
+impl QuoteLookupBot<Vec<InlineQueryResult>> for InlineBot {}
 impl ThesaurusLookupBot<Vec<InlineQueryResult>> for InlineBot {}
 impl UrbanLookupBot<Vec<InlineQueryResult>> for InlineBot {}
 impl SuggestionsBot for InlineBot {}
 
 impl WordFinderBot<Vec<InlineQueryResult>> for InlineBot {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use teloxide::types::{InlineQueryResultArticle, InputMessageContent, InputMessageContentText};
+
+    /// Makes an inline bot for the query "run" scrolled to `offset`, with 60 results kept for it.
+    fn inline_bot(offset: &str) -> InlineBot {
+        let query = serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "from": {"id": 1, "is_bot": false, "first_name": "User"},
+            "query": "run",
+            "offset": offset,
+        }))
+        .unwrap();
+        let results = (0..60)
+            .map(|i| {
+                InlineQueryResult::Article(InlineQueryResultArticle::new(
+                    i.to_string(),
+                    i.to_string(),
+                    InputMessageContent::Text(InputMessageContentText::new(i.to_string())),
+                ))
+            })
+            .collect::<Vec<_>>();
+        let pages = InlinePages::default();
+        pages.insert(teloxide::types::UserId(1), "run", &results);
+        InlineBot {
+            bot: Bot::new("token"),
+            query,
+            settings: ChatSettings::default(),
+            pages,
+        }
+    }
+
+    #[test]
+    fn next_pages_are_taken_from_kept_results() {
+        assert_eq!(inline_bot("50").kept_results().map(|results| results.len()), Some(60));
+    }
+
+    #[test]
+    fn first_page_is_looked_up_anew() {
+        assert_eq!(inline_bot("").kept_results(), None);
+    }
+}
@@ -1,15 +1,20 @@
 use crate::bloc::common::LookupError;
 use crate::format::LookupFormatter;
+use crate::settings::ChatSettings;
+use teloxide::types::InlineKeyboardMarkup;
 
 pub trait LookupBot: Clone {
     type Request: Clone + Send + Sync;
     type Formatter: LookupFormatter + Default;
     type Response: Clone + Send + Sync + Default;
 
-    /// Returns the default formatter for this bot.
+    /// Returns the settings of the chat the bot is answering in.
+    fn settings(&self) -> &ChatSettings;
+
+    /// Returns the formatter for this bot.
     ///
     /// The formatter is created by calling `Formatter::default()` for the bot's associated
-    /// `Formatter` type.
+    /// `Formatter` type and is given the settings of the chat the bot is answering in.
     ///
     /// # Examples
     ///
@@ -20,7 +25,7 @@ pub trait LookupBot: Clone {
     /// // `formatter` is the same as `default` for the bot's Formatter type.
     /// ```
     fn formatter(&self) -> Self::Formatter {
-        Self::Formatter::default()
+        Self::Formatter::default().with_settings(self.settings().clone())
     }
 
     /// Returns the canonical error response for failed lookup operations.
@@ -40,6 +45,12 @@ pub trait LookupBot: Clone {
     }
 
     fn answer(&self, response: Self::Response) -> impl Future<Output = anyhow::Result<()>> + Send;
+
+    fn answer_with_keyboard(
+        &self,
+        response: Self::Response,
+        keyboard: InlineKeyboardMarkup,
+    ) -> impl Future<Output = anyhow::Result<()>> + Send;
 }
 
 pub trait LookupBotX: LookupBot {
@@ -155,6 +166,22 @@ pub trait LookupBotX: LookupBot {
     /// // bot.respond(response).await.unwrap();
     /// ```
     fn respond(&self, response: Self::Response) -> impl Future<Output = anyhow::Result<()>> + Send;
+
+    /// Send a response with an inline keyboard attached, falling back to the bot's generic error response if sending fails.
+    ///
+    /// Behaves like `respond`, but delivers the response via `answer_with_keyboard`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// // Given an implementor `bot` of `LookupBot`:
+    /// // bot.respond_with_keyboard(response, keyboard).await.unwrap();
+    /// ```
+    fn respond_with_keyboard(
+        &self,
+        response: Self::Response,
+        keyboard: InlineKeyboardMarkup,
+    ) -> impl Future<Output = anyhow::Result<()>> + Send;
 }
 
 impl<T> LookupBotX for T
@@ -224,4 +251,17 @@ where
         }
         Ok(())
     }
+
+    async fn respond_with_keyboard(
+        &self,
+        response: Self::Response,
+        keyboard: InlineKeyboardMarkup,
+    ) -> anyhow::Result<()> {
+        let res = self.answer_with_keyboard(response, keyboard).await;
+        if let Err(e) = res {
+            log::error!("Couldn't send response: {:?}", e);
+            let _ = self.answer_generic_err().await;
+        }
+        Ok(())
+    }
 }
@@ -1,5 +1,14 @@
+use crate::bloc::abbr_lookup::AbbrLookupBot;
+use crate::bloc::all_lookup::AllLookupBot;
+use crate::bloc::auto_lookup::AutoLookupBot;
+use crate::bloc::etymology::EtymologyBot;
 use crate::bloc::help::HelpBot;
+use crate::bloc::pattern_finder::PatternFinderBot;
 use crate::bloc::phrase_lookup::PhraseLookupBot;
+use crate::bloc::pronunciation::PronunciationBot;
+use crate::bloc::quote_lookup::QuoteLookupBot;
+use crate::bloc::reverse_lookup::ReverseLookupBot;
+use crate::bloc::settings::{SettingsBot, may_change_settings};
 use crate::bloc::start::StartBot;
 use crate::bloc::teapot::TeapotBot;
 use crate::bloc::thesaurus_lookup::ThesaurusLookupBot;
@@ -8,19 +17,81 @@ use crate::bloc::urban_lookup::UrbanLookupBot;
 use crate::bloc::word_finder::WordFinderBot;
 use crate::bloc::word_lookup::WordLookupBot;
 use crate::bloc::wordle::WordleBot;
-use crate::bot::LookupBot;
+use crate::bot::{LookupBot, SentReplies};
 use crate::commands::{FullMessageFormatter, MessageCommands};
-use crate::format::ToEscaped;
-use teloxide::Bot;
-use teloxide::payloads::SendMessageSetters;
+use crate::format::{ToEscaped, truncate_message};
+use crate::settings::ChatSettings;
+use teloxide::payloads::{EditMessageTextSetters, SendAudioSetters, SendMessageSetters};
 use teloxide::prelude::Requester;
-use teloxide::types::{Message, ParseMode};
+use teloxide::types::{
+    ChatId, InlineKeyboardMarkup, InputFile, Message, MessageId, ParseMode, ReplyParameters,
+};
 use teloxide::utils::command::BotCommands;
+use teloxide::{ApiError, Bot, RequestError};
 
 #[derive(Debug, Clone)]
 pub struct MessageBot {
     pub bot: Bot,
     pub message: Message,
+    pub settings: ChatSettings,
+    /// The message the responses are threaded to, if not the one being answered.
+    pub reply_to: Option<MessageId>,
+    /// The bot's replies to recent messages, edited when those messages are.
+    pub replies: SentReplies,
+}
+
+impl MessageBot {
+    /// Sends the text to the chat with `ParseMode::MarkdownV2` and the keyboard, if any, attached,
+    /// as a reply to `self.reply_to` if set.
+    ///
+    /// When the message being answered is an edited one, the bot's previous reply to it is edited instead,
+    /// falling back to sending a new reply if there's none or it can't be edited.
+    /// The reply is remembered in `self.replies` for the message's next edit.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if sending the message fails.
+    async fn send_text(
+        &self,
+        text: String,
+        keyboard: Option<InlineKeyboardMarkup>,
+    ) -> anyhow::Result<()> {
+        let chat = self.message.chat.id;
+        let previous = self
+            .message
+            .edit_date()
+            .and_then(|_| self.replies.get(chat, self.message.id));
+        if let Some(previous) = previous {
+            let mut request = self
+                .bot
+                .edit_message_text(chat, previous, text.clone())
+                .parse_mode(ParseMode::MarkdownV2);
+            if let Some(keyboard) = keyboard.clone() {
+                request = request.reply_markup(keyboard);
+            }
+            match request.await {
+                Ok(_) | Err(RequestError::Api(ApiError::MessageNotModified)) => return Ok(()),
+                Err(err) => log::warn!(
+                    "Could not edit reply {:?}, sending anew: {:?}",
+                    previous,
+                    err
+                ),
+            }
+        }
+        let mut request = self
+            .bot
+            .send_message(chat, text)
+            .parse_mode(ParseMode::MarkdownV2);
+        if let Some(keyboard) = keyboard {
+            request = request.reply_markup(keyboard);
+        }
+        if let Some(reply_to) = self.reply_to {
+            request = request.reply_parameters(ReplyParameters::new(reply_to));
+        }
+        let sent = request.await?;
+        self.replies.insert(chat, self.message.id, sent.id);
+        Ok(())
+    }
 }
 
 impl LookupBot for MessageBot {
@@ -28,6 +99,10 @@ impl LookupBot for MessageBot {
     type Formatter = FullMessageFormatter;
     type Response = String;
 
+    fn settings(&self) -> &ChatSettings {
+        &self.settings
+    }
+
     /// Produces a short, polite error message to present when a query cannot be processed.
     ///
     /// # Returns
@@ -48,7 +123,8 @@ impl LookupBot for MessageBot {
 
     /// Sends the given text as a message to the chat referenced by this instance's `message`, using MarkdownV2 parsing.
     ///
-    /// The message is delivered to `self.message.chat.id` with `ParseMode::MarkdownV2`.
+    /// The message is delivered to `self.message.chat.id` with `ParseMode::MarkdownV2`,
+    /// as a reply to `self.reply_to` if set. An edited message gets the previous reply edited instead.
     ///
     /// # Errors
     ///
@@ -63,12 +139,25 @@ impl LookupBot for MessageBot {
     /// # }
     /// ```
     async fn answer(&self, text: String) -> anyhow::Result<()> {
-        let _ = self
-            .bot
-            .send_message(self.message.chat.id, text)
-            .parse_mode(ParseMode::MarkdownV2)
-            .await?;
-        Ok(())
+        self.send_text(text, None).await
+    }
+
+    /// Sends the given text with an inline keyboard attached, using MarkdownV2 parsing.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example_usage(bot: &crate::bot::MessageBot) -> anyhow::Result<()> {
+    /// bot.answer_with_keyboard("Pick one".to_string(), InlineKeyboardMarkup::default()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn answer_with_keyboard(
+        &self,
+        text: String,
+        keyboard: InlineKeyboardMarkup,
+    ) -> anyhow::Result<()> {
+        self.send_text(text, Some(keyboard)).await
     }
 }
 
@@ -93,6 +182,68 @@ impl StartBot<String> for MessageBot {
     }
 }
 
+impl AutoLookupBot<String> for MessageBot {
+    /// Confirms whether plain single-word messages are now looked up in the chat.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// let msg = bot.auto_lookup_response(true);
+    /// assert!(msg.contains("will look up"));
+    /// ```
+    fn auto_lookup_response(&self, enabled: bool) -> String {
+        match enabled {
+            true => "Got it, I will look up every single-word message in this chat.",
+            false => "Got it, I will only answer commands, mentions and replies in this chat.",
+        }
+        .to_string()
+        .to_escaped()
+    }
+}
+
+impl SettingsBot<String> for MessageBot {
+    /// The chat the command was sent to.
+    fn chat(&self) -> ChatId {
+        self.message.chat.id
+    }
+
+    /// Whether the command's sender may change the chat's settings, see [`may_change_settings`].
+    ///
+    /// Messages without a sender, e.g. the ones posted on behalf of a channel, may not.
+    async fn may_change_settings(&self) -> bool {
+        let user = self.message.from.as_ref().map(|user| user.id);
+        may_change_settings(&self.bot, self.message.chat.id, user).await
+    }
+
+    /// Explains the settings menu, escaped for MarkdownV2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let msg = <MessageBot as SettingsBot<String>>::settings_response();
+    /// assert!(msg.contains("Tap a setting"));
+    /// ```
+    fn settings_response() -> String {
+        "Settings of this chat. Tap a setting to change it:"
+            .to_string()
+            .to_escaped()
+    }
+
+    /// Explains that the settings of a group are only changed by its admins, escaped for MarkdownV2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let msg = <MessageBot as SettingsBot<String>>::on_forbidden();
+    /// assert!(msg.contains("admins"));
+    /// ```
+    fn on_forbidden() -> String {
+        "Sorry, only the admins of this chat can change its settings."
+            .to_string()
+            .to_escaped()
+    }
+}
+
 impl HelpBot<String> for MessageBot {
     /// Provides help text listing the bot's available commands, escaped for MarkdownV2.
     ///
@@ -179,6 +330,164 @@ impl WordLookupBot<String> for MessageBot {
     }
 }
 
+impl AbbrLookupBot<String> for MessageBot {
+    /// Guidance shown when the user does not provide an abbreviation to look up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let msg = on_empty();
+    /// assert!(msg.contains("specify an abbreviation"));
+    /// ```
+    fn on_empty() -> String {
+        "You need to specify an abbreviation to look up, like so: `\\abbr asap`, \
+        optionally followed by a category: `\\abbr asap business`"
+            .to_string()
+            .to_escaped()
+    }
+
+    /// Message shown when Stands4 knows no definitions of the abbreviation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let msg = on_unknown();
+    /// assert!(msg.contains("abbreviation"));
+    /// ```
+    fn on_unknown() -> String {
+        "Sorry, I don't know what that abbreviation stands for."
+            .to_string()
+            .to_escaped()
+    }
+}
+
+impl AllLookupBot<String> for MessageBot {
+    /// Guidance shown when the user does not provide a term to look up in every source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let msg = on_empty();
+    /// assert!(msg.contains("every source"));
+    /// ```
+    fn on_empty() -> String {
+        "You need to specify a term to look up in every source, like so: `\\all cookie`"
+            .to_string()
+            .to_escaped()
+    }
+
+    /// Message shown when none of the sources knows the term.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let msg = on_unknown();
+    /// assert!(msg.contains("none of the sources"));
+    /// ```
+    fn on_unknown() -> String {
+        "Sorry, none of the sources know that term."
+            .to_string()
+            .to_escaped()
+    }
+
+    /// Cuts the reply down to Telegram's message length limit, see [`truncate_message`].
+    fn fit_response(response: String) -> String {
+        truncate_message(response)
+    }
+}
+
+impl EtymologyBot<String> for MessageBot {
+    /// Guidance shown when the user does not provide a word to trace back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let msg = on_empty();
+    /// assert!(msg.contains("specify a word"));
+    /// ```
+    fn on_empty() -> String {
+        "You need to specify a word to find the origin of, like so: `\\etym give`"
+            .to_string()
+            .to_escaped()
+    }
+
+    /// Message shown when Wiktionary knows nothing about the word's origin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let msg = on_unknown();
+    /// assert!(msg.contains("origin"));
+    /// ```
+    fn on_unknown() -> String {
+        "Sorry, I don't know the origin of that word."
+            .to_string()
+            .to_escaped()
+    }
+}
+
+impl PronunciationBot<String> for MessageBot {
+    /// Guidance shown when the user does not provide a word to pronounce.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let msg = on_empty();
+    /// assert!(msg.contains("specify a word"));
+    /// ```
+    fn on_empty() -> String {
+        "You need to specify a word to pronounce, like so: `\\say give`"
+            .to_string()
+            .to_escaped()
+    }
+
+    /// Message shown when neither a recording nor a transcription of the word is known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let msg = on_unknown();
+    /// assert!(msg.contains("don't know how"));
+    /// ```
+    fn on_unknown() -> String {
+        "Sorry, I don't know how to pronounce that word."
+            .to_string()
+            .to_escaped()
+    }
+
+    /// Sends the recording as an audio message to the chat, captioned with the word and its transcription.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the recording URL is malformed or sending the audio fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example_usage(bot: &crate::bot::MessageBot) -> anyhow::Result<()> {
+    /// bot.send_audio("hello", "https://example.com/hello.mp3", Some("həˈləʊ")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn send_audio(&self, word: &str, audio: &str, ipa: Option<&str>) -> anyhow::Result<()> {
+        let caption = match ipa {
+            Some(ipa) => format!("{} /{}/", word, ipa),
+            None => word.to_string(),
+        };
+        let mut request = self
+            .bot
+            .send_audio(self.message.chat.id, InputFile::url(audio.parse()?))
+            .caption(caption);
+        if let Some(reply_to) = self.reply_to {
+            request = request.reply_parameters(ReplyParameters::new(reply_to));
+        }
+        let sent = request.await?;
+        self.replies
+            .insert(self.message.chat.id, self.message.id, sent.id);
+        Ok(())
+    }
+}
+
 impl PhraseLookupBot<String> for MessageBot {
     /// Instructs the user to provide a phrase and shows a sample invocation.
     ///
@@ -197,6 +506,22 @@ impl PhraseLookupBot<String> for MessageBot {
     }
 }
 
+impl QuoteLookupBot<String> for MessageBot {
+    /// Instructs the user to provide a keyword or an author and shows a sample invocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let msg = on_empty();
+    /// assert!(msg.contains("keyword or an author"));
+    /// ```
+    fn on_empty() -> String {
+        "You need to specify a keyword or an author to find quotes of, like so: `\\quote courage`"
+            .to_string()
+            .to_escaped()
+    }
+}
+
 impl ThesaurusLookupBot<String> for MessageBot {
     /// Provides guidance instructing the user to supply a phrase for a thesaurus lookup.
     ///
@@ -219,6 +544,22 @@ impl ThesaurusLookupBot<String> for MessageBot {
     }
 }
 
+impl ReverseLookupBot<String> for MessageBot {
+    /// Guidance shown when the user does not describe the word they are looking for.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let msg = on_empty();
+    /// assert!(msg.contains("describe the word"));
+    /// ```
+    fn on_empty() -> String {
+        "You need to describe the word you're looking for, like so: `\\reverse a person who hoards books`"
+            .to_string()
+            .to_escaped()
+    }
+}
+
 impl UrbanLookupBot<String> for MessageBot {
     /// Provide a MarkdownV2-escaped hint showing how to specify a phrase for the Urban lookup command.
     ///
@@ -237,6 +578,20 @@ impl UrbanLookupBot<String> for MessageBot {
             .to_string()
             .to_escaped()
     }
+
+    /// Tells the user that Urban Dictionary is switched off in this chat and where to switch it back on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let msg = <MessageBot as UrbanLookupBot<String>>::on_disabled();
+    /// assert!(msg.contains("/settings"));
+    /// ```
+    fn on_disabled() -> String {
+        "Urban Dictionary is switched off in this chat, it can be switched back on in /settings"
+            .to_string()
+            .to_escaped()
+    }
 }
 
 impl WordFinderBot<String> for MessageBot {
@@ -260,20 +615,38 @@ impl WordFinderBot<String> for MessageBot {
             .to_escaped()
     }
 
-    /// Message shown when a finder query uses an invalid number of symbols.
+    /// Message shown when a finder query describes an invalid number of letters.
     ///
-    /// Returns a `String` explaining that the finder accepts between two and fifteen symbols.
+    /// Returns a `String` explaining that the finder accepts between two and fifteen letters.
     ///
     /// # Examples
     ///
     /// ```
     /// assert_eq!(
     ///     crate::on_length_invalid(),
-    ///     "Sorry, finder can only process up to 15 symbols, but at least two".to_string()
+    ///     "Sorry, finder can only process up to 15 letters, but at least two".to_string()
     /// );
     /// ```
     fn on_length_invalid() -> String {
-        "Sorry, finder can only process up to 15 symbols, but at least two"
+        "Sorry, finder can only process up to 15 letters, but at least two"
+            .to_string()
+            .to_escaped()
+    }
+
+    /// Message shown when a clue query is missing either the mask or the clue.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the guidance text and an example query.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let msg = MessageBot::on_empty_clue();
+    /// assert!(msg.contains("clue c_t, feline pet"));
+    /// ```
+    fn on_empty_clue() -> String {
+        "You need to specify a mask and a clue to run query for, like so: `\\clue c_t, feline pet`"
             .to_string()
             .to_escaped()
     }
@@ -297,8 +670,9 @@ impl WordFinderBot<String> for MessageBot {
     /// ```
     fn on_wrong_format() -> String {
         "Sorry, your message is in the wrong format, you can only specify:\
-        1. a-Z and an underscore characters for query, up to 15 chars;\
-        2. a-Z characters for banned list, up to 13 chars"
+        1. a-Z characters and `_`, `*`, `#`, `@`, `[...]` wildcards for query, up to 15 letters;\
+        2. a-Z characters for banned list, up to 13 chars;\
+        3. `sort=alpha` or `min_freq=N` options at the end"
             .to_string()
             .to_escaped()
     }
@@ -317,13 +691,142 @@ impl WordFinderBot<String> for MessageBot {
     ///
     /// ```
     /// let msg = on_invalid_query();
-    /// assert!(msg.contains("all underscores") && msg.contains("no underscore"));
+    /// assert!(msg.contains("no known letters") && msg.contains("no wildcards"));
     /// ```
     fn on_invalid_query() -> String {
         "Your query is incorrect: \
-        it either has all underscores, which would result in a whole dictionary of response, \
-        or no underscore, in which case you already know the word!"
+        it either has no known letters, which would result in a whole dictionary of response, \
+        or no wildcards, in which case you already know the word!"
+            .to_string()
+            .to_escaped()
+    }
+}
+
+impl PatternFinderBot<String> for MessageBot {
+    /// Guidance shown when the user does not provide a pattern to find words by.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let msg = on_empty();
+    /// assert!(msg.contains("specify a pattern"));
+    /// ```
+    fn on_empty() -> String {
+        "You need to specify a pattern to run query for, like so: `\\pattern abca`"
+            .to_string()
+            .to_escaped()
+    }
+
+    /// Explains the limits of the pattern's length and of the length filter, escaped for MarkdownV2.
+    fn on_length_invalid() -> String {
+        "Sorry, the pattern can only be from 2 to 15 letters long, \
+        and the length filter can't be shorter than the pattern or longer than 15"
             .to_string()
             .to_escaped()
     }
-}
\ No newline at end of file
+
+    /// Lists what a pattern may consist of when the user's one can't be parsed, escaped for MarkdownV2.
+    fn on_wrong_format() -> String {
+        "Sorry, your message is in the wrong format, you can only specify:\
+        1. a-Z characters for the pattern, up to 15 chars;\
+        2. optionally, the length of the words after a comma"
+            .to_string()
+            .to_escaped()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::networking::fixtures::serve;
+    use axum::extract::Path;
+    use axum::routing::post;
+    use std::sync::{Arc, Mutex};
+
+    const NOT_MODIFIED: &str = "Bad Request: message is not modified: specified new message content and reply markup are \
+        exactly the same as a current content and reply markup of the message";
+
+    fn message(id: i32, edited: bool) -> Message {
+        let mut message = serde_json::json!({
+            "message_id": id,
+            "date": 0,
+            "chat": { "id": 1, "type": "private", "first_name": "Jane" },
+            "text": "run",
+        });
+        if edited {
+            message["edit_date"] = serde_json::json!(1);
+        }
+        serde_json::from_value(message).unwrap()
+    }
+
+    /// Serves a fixture Bot API recording the called methods, its editMessageText answering
+    /// with `edit_result`, and returns a bot reaching it along with the called methods.
+    async fn fixture_bot(edit_result: serde_json::Value) -> (Bot, Arc<Mutex<Vec<String>>>) {
+        let called = Arc::new(Mutex::new(vec![]));
+        let methods = called.clone();
+        let app = axum::Router::new().route(
+            "/{token}/{method}",
+            post(
+                move |Path((_, method)): Path<(String, String)>| async move {
+                    methods.lock().unwrap().push(method.clone());
+                    let response = match method.as_str() {
+                        "EditMessageText" => edit_result.clone(),
+                        _ => serde_json::json!({ "ok": true, "result": message(20, false) }),
+                    };
+                    axum::Json(response)
+                },
+            ),
+        );
+        let url = reqwest::Url::parse(&serve(app).await).unwrap();
+        (Bot::new("token").set_api_url(url), called)
+    }
+
+    fn message_bot(bot: Bot, message: Message, replies: SentReplies) -> MessageBot {
+        MessageBot {
+            bot,
+            message,
+            settings: Default::default(),
+            reply_to: None,
+            replies,
+        }
+    }
+
+    #[tokio::test]
+    async fn new_message_gets_new_reply() {
+        let (bot, called) = fixture_bot(serde_json::json!({ "ok": true, "result": true })).await;
+        let replies = SentReplies::default();
+        let bot = message_bot(bot, message(10, false), replies.clone());
+        bot.send_text("ran".to_string(), None).await.unwrap();
+        assert_eq!(*called.lock().unwrap(), vec!["SendMessage"]);
+        assert_eq!(replies.get(ChatId(1), MessageId(10)), Some(MessageId(20)));
+    }
+
+    #[tokio::test]
+    async fn edited_message_gets_previous_reply_edited() {
+        let (bot, called) = fixture_bot(serde_json::json!({
+            "ok": true,
+            "result": message(11, false),
+        }))
+        .await;
+        let replies = SentReplies::default();
+        replies.insert(ChatId(1), MessageId(10), MessageId(11));
+        let bot = message_bot(bot, message(10, true), replies);
+        bot.send_text("ran".to_string(), None).await.unwrap();
+        assert_eq!(*called.lock().unwrap(), vec!["EditMessageText"]);
+    }
+
+    #[tokio::test]
+    async fn unchanged_reply_is_not_sent_anew() {
+        let (bot, called) = fixture_bot(serde_json::json!({
+            "ok": false,
+            "error_code": 400,
+            "description": NOT_MODIFIED,
+        }))
+        .await;
+        let replies = SentReplies::default();
+        replies.insert(ChatId(1), MessageId(10), MessageId(11));
+        let bot = message_bot(bot, message(10, true), replies);
+        bot.send_text("ran".to_string(), None).await.unwrap();
+        assert_eq!(*called.lock().unwrap(), vec!["EditMessageText"]);
+    }
+}
@@ -1,8 +1,12 @@
+pub mod callback_bot;
 pub mod inline_bot;
 pub mod lookup_bot;
 pub mod message_bot;
 pub mod runner;
+pub mod sent_replies;
 
+pub use callback_bot::*;
 pub use inline_bot::*;
 pub use lookup_bot::*;
 pub use message_bot::*;
+pub use sent_replies::*;
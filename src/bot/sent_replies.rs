@@ -0,0 +1,88 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use teloxide::types::{ChatId, MessageId};
+
+/// How many replies are remembered before the oldest ones are forgotten.
+const MAX_REMEMBERED_REPLIES: usize = 1000;
+
+#[derive(Debug, Default)]
+struct Replies {
+    by_message: HashMap<(ChatId, MessageId), MessageId>,
+    order: VecDeque<(ChatId, MessageId)>,
+}
+
+/// The bot's replies to the most recent user messages, so that an edited message
+/// gets its previous reply edited instead of a new one.
+///
+/// Only the last `capacity` messages are remembered, the older ones are answered anew when edited.
+#[derive(Debug, Clone)]
+pub struct SentReplies {
+    replies: Arc<Mutex<Replies>>,
+    capacity: usize,
+}
+
+impl SentReplies {
+    /// Creates an empty map remembering the replies to at most `capacity` messages.
+    pub fn new(capacity: usize) -> Self {
+        SentReplies {
+            replies: Arc::default(),
+            capacity,
+        }
+    }
+
+    /// Returns the bot's reply to the user's message, if it's still remembered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let replies = SentReplies::default();
+    /// replies.insert(ChatId(1), MessageId(10), MessageId(11));
+    /// assert_eq!(replies.get(ChatId(1), MessageId(10)), Some(MessageId(11)));
+    /// ```
+    pub fn get(&self, chat: ChatId, message: MessageId) -> Option<MessageId> {
+        self.replies
+            .lock()
+            .unwrap()
+            .by_message
+            .get(&(chat, message))
+            .copied()
+    }
+
+    /// Remembers the bot's reply to the user's message, forgetting the oldest message if there are too many.
+    pub fn insert(&self, chat: ChatId, message: MessageId, reply: MessageId) {
+        let mut replies = self.replies.lock().unwrap();
+        if replies.by_message.insert((chat, message), reply).is_some() {
+            return;
+        }
+        replies.order.push_back((chat, message));
+        while replies.order.len() > self.capacity {
+            if let Some(oldest) = replies.order.pop_front() {
+                replies.by_message.remove(&oldest);
+            }
+        }
+    }
+}
+
+impl Default for SentReplies {
+    fn default() -> Self {
+        Self::new(MAX_REMEMBERED_REPLIES)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn oldest_replies_are_forgotten() {
+        let replies = SentReplies::new(2);
+        replies.insert(ChatId(1), MessageId(1), MessageId(2));
+        replies.insert(ChatId(1), MessageId(3), MessageId(4));
+        replies.insert(ChatId(1), MessageId(1), MessageId(5));
+        replies.insert(ChatId(2), MessageId(1), MessageId(6));
+
+        assert_eq!(replies.get(ChatId(1), MessageId(1)), None);
+        assert_eq!(replies.get(ChatId(1), MessageId(3)), Some(MessageId(4)));
+        assert_eq!(replies.get(ChatId(2), MessageId(1)), Some(MessageId(6)));
+    }
+}
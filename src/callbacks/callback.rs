@@ -0,0 +1,539 @@
+use crate::bloc::abbr_lookup::AbbrLookupHandler;
+use crate::bloc::common::CommandHandler;
+use crate::bloc::etymology::EtymologyHandler;
+use crate::bloc::phrase_lookup::PhraseLookupHandler;
+use crate::bloc::pronunciation::PronunciationHandler;
+use crate::bloc::settings::SettingsHandler;
+use crate::bloc::thesaurus_lookup::ThesaurusLookupHandler;
+use crate::bloc::urban_lookup::UrbanLookupHandler;
+use crate::bloc::word_lookup::WordLookupHandler;
+use crate::bot::CallbackBot;
+use crate::settings::{ChatSettings, Setting, SettingsStore};
+use teloxide::Bot;
+use teloxide::dispatching::UpdateFilterExt;
+use teloxide::prelude::Requester;
+use teloxide::types::{CallbackQuery, InlineKeyboardButton, InlineKeyboardMarkup, Update};
+
+/// Telegram refuses callback data longer than this many bytes.
+const MAX_DATA_LEN: usize = 64;
+/// How many words at most are offered to pick from.
+const MAX_PICKED_WORDS: usize = 12;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CallbackCommands {
+    WordLookup(String),
+    PhraseLookup(String),
+    Thesaurus(String),
+    Urban(String),
+    Etymology(String),
+    Abbreviations(String),
+    Say(String),
+    Settings(Setting),
+}
+
+/// A source a lookup result can be switched to with the row of buttons under it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ResultSource {
+    Dictionary,
+    Thesaurus,
+    Urban,
+    Abbreviations,
+}
+
+impl ResultSource {
+    const ALL: [ResultSource; 4] = [
+        ResultSource::Dictionary,
+        ResultSource::Thesaurus,
+        ResultSource::Urban,
+        ResultSource::Abbreviations,
+    ];
+
+    /// Label of the source's button.
+    fn name(&self) -> &'static str {
+        match self {
+            ResultSource::Dictionary => "Dictionary",
+            ResultSource::Thesaurus => "Thesaurus",
+            ResultSource::Urban => "Urban",
+            ResultSource::Abbreviations => "Abbreviations",
+        }
+    }
+
+    /// The command looking the term up in this source.
+    ///
+    /// The dictionary looks a phrase up as a whole, while abbreviations are only looked up for a single word,
+    /// since the rest of the query would be taken for a category.
+    fn command(&self, term: &str) -> Option<CallbackCommands> {
+        let single_word = !term.contains(char::is_whitespace);
+        let term = term.to_string();
+        match self {
+            ResultSource::Dictionary if single_word => Some(CallbackCommands::WordLookup(term)),
+            ResultSource::Dictionary => Some(CallbackCommands::PhraseLookup(term)),
+            ResultSource::Thesaurus => Some(CallbackCommands::Thesaurus(term)),
+            ResultSource::Urban => Some(CallbackCommands::Urban(term)),
+            ResultSource::Abbreviations => {
+                single_word.then_some(CallbackCommands::Abbreviations(term))
+            }
+        }
+    }
+}
+
+impl CallbackCommands {
+    /// Serializes the command into the data attached to an inline keyboard button.
+    ///
+    /// The data is a short tag followed by a colon and the command's argument, e.g. `"w:receive"`.
+    ///
+    /// # Returns
+    ///
+    /// `Some(String)` with the data, or `None` if it would exceed Telegram's 64-byte limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let data = CallbackCommands::WordLookup("receive".to_string()).to_data();
+    /// assert_eq!(data, Some("w:receive".to_string()));
+    /// ```
+    pub fn to_data(&self) -> Option<String> {
+        let data = match self {
+            CallbackCommands::WordLookup(word) => format!("w:{}", word),
+            CallbackCommands::PhraseLookup(phrase) => format!("p:{}", phrase),
+            CallbackCommands::Thesaurus(phrase) => format!("t:{}", phrase),
+            CallbackCommands::Urban(phrase) => format!("u:{}", phrase),
+            CallbackCommands::Etymology(word) => format!("o:{}", word),
+            CallbackCommands::Abbreviations(query) => format!("a:{}", query),
+            CallbackCommands::Say(word) => format!("v:{}", word),
+            CallbackCommands::Settings(setting) => format!("s:{}", setting.tag()),
+        };
+        (data.len() <= MAX_DATA_LEN).then_some(data)
+    }
+
+    /// Parses the data of a pressed inline keyboard button back into a command.
+    ///
+    /// # Returns
+    ///
+    /// `Some(CallbackCommands)` if the data was produced by [`CallbackCommands::to_data`], `None` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let command = CallbackCommands::parse("w:receive");
+    /// assert_eq!(command, Some(CallbackCommands::WordLookup("receive".to_string())));
+    /// ```
+    pub fn parse(data: &str) -> Option<Self> {
+        match data.split_once(':')? {
+            ("w", word) if !word.is_empty() => Some(CallbackCommands::WordLookup(word.to_string())),
+            ("p", phrase) if !phrase.is_empty() => {
+                Some(CallbackCommands::PhraseLookup(phrase.to_string()))
+            }
+            ("t", phrase) if !phrase.is_empty() => {
+                Some(CallbackCommands::Thesaurus(phrase.to_string()))
+            }
+            ("u", phrase) if !phrase.is_empty() => {
+                Some(CallbackCommands::Urban(phrase.to_string()))
+            }
+            ("o", word) if !word.is_empty() => Some(CallbackCommands::Etymology(word.to_string())),
+            ("a", query) if !query.is_empty() => {
+                Some(CallbackCommands::Abbreviations(query.to_string()))
+            }
+            ("v", word) if !word.is_empty() => Some(CallbackCommands::Say(word.to_string())),
+            ("s", tag) => Setting::from_tag(tag).map(CallbackCommands::Settings),
+            _ => None,
+        }
+    }
+}
+
+/// Builds a keyboard with a button per word, each of which looks the word up when pressed.
+///
+/// Words that do not fit into the callback data are left out.
+///
+/// # Examples
+///
+/// ```
+/// let keyboard = word_lookup_keyboard(&["receive".to_string(), "relieve".to_string()]);
+/// assert_eq!(keyboard.inline_keyboard[0].len(), 2);
+/// ```
+pub fn word_lookup_keyboard(words: &[String]) -> InlineKeyboardMarkup {
+    let buttons = words.iter().filter_map(|word| {
+        CallbackCommands::WordLookup(word.clone())
+            .to_data()
+            .map(|data| InlineKeyboardButton::callback(word, data))
+    });
+    InlineKeyboardMarkup::new([buttons])
+}
+
+/// Builds a keyboard with a single "Origin" button, which shows the word's etymology when pressed.
+///
+/// The keyboard is empty if the word does not fit into the callback data.
+///
+/// # Examples
+///
+/// ```
+/// let keyboard = origin_keyboard("run");
+/// assert_eq!(keyboard.inline_keyboard[0][0].text, "Origin");
+/// ```
+pub fn origin_keyboard(word: &str) -> InlineKeyboardMarkup {
+    single_button_keyboard("Origin", CallbackCommands::Etymology(word.to_string()))
+}
+
+/// Builds a keyboard to pick one of the words with, three buttons a row, each running `command` on its word.
+///
+/// Words that don't fit into the callback data are skipped, as are the ones beyond the first `MAX_PICKED_WORDS`.
+///
+/// # Examples
+///
+/// ```
+/// let words = vec!["what".to_string(), "a".to_string(), "serendipity".to_string()];
+/// let keyboard = pick_keyboard(&words, CallbackCommands::Etymology);
+/// assert_eq!(keyboard.inline_keyboard[0].len(), 3);
+/// ```
+pub fn pick_keyboard(
+    words: &[String],
+    command: fn(String) -> CallbackCommands,
+) -> InlineKeyboardMarkup {
+    let buttons = words
+        .iter()
+        .filter_map(|word| {
+            command(word.clone())
+                .to_data()
+                .map(|data| InlineKeyboardButton::callback(word, data))
+        })
+        .take(MAX_PICKED_WORDS)
+        .collect::<Vec<_>>();
+    InlineKeyboardMarkup::new(buttons.chunks(3).map(<[_]>::to_vec))
+}
+
+/// Builds a keyboard with a single "Definitions" button, which looks the word up when pressed.
+///
+/// The keyboard is empty if the word does not fit into the callback data.
+///
+/// # Examples
+///
+/// ```
+/// let keyboard = definitions_keyboard("run");
+/// assert_eq!(keyboard.inline_keyboard[0][0].text, "Definitions");
+/// ```
+pub fn definitions_keyboard(word: &str) -> InlineKeyboardMarkup {
+    single_button_keyboard(
+        "Definitions",
+        CallbackCommands::WordLookup(word.to_string()),
+    )
+}
+
+/// Builds a keyboard with a button per abbreviation category, two in a row, each of which
+/// expands the category in place when pressed.
+///
+/// Every button is labeled with the category and the number of its definitions.
+/// Categories that do not fit into the callback data are left out.
+///
+/// # Examples
+///
+/// ```
+/// let keyboard = abbreviation_categories_keyboard("asap", &[("Computing", 2), ("Other", 5)]);
+/// assert_eq!(keyboard.inline_keyboard[0][1].text, "Other (5)");
+/// ```
+pub fn abbreviation_categories_keyboard(
+    term: &str,
+    categories: &[(&str, usize)],
+) -> InlineKeyboardMarkup {
+    let buttons = categories
+        .iter()
+        .filter_map(|(category, count)| {
+            CallbackCommands::Abbreviations(format!("{} {}", term, category))
+                .to_data()
+                .map(|data| {
+                    InlineKeyboardButton::callback(format!("{} ({})", category, count), data)
+                })
+        })
+        .collect::<Vec<_>>();
+    InlineKeyboardMarkup::new(buttons.chunks(2).map(<[_]>::to_vec))
+}
+
+/// Builds a keyboard with a single "All categories" button, which brings back the overview of
+/// the abbreviation's categories when pressed.
+///
+/// The keyboard is empty if the term does not fit into the callback data.
+///
+/// # Examples
+///
+/// ```
+/// let keyboard = all_categories_keyboard("asap");
+/// assert_eq!(keyboard.inline_keyboard[0][0].text, "All categories");
+/// ```
+pub fn all_categories_keyboard(term: &str) -> InlineKeyboardMarkup {
+    single_button_keyboard(
+        "All categories",
+        CallbackCommands::Abbreviations(term.to_string()),
+    )
+}
+
+/// Adds a row of buttons under the keyboard, each of which shows the result of looking the term up
+/// in another source in place of the current one.
+///
+/// The current source is left out, as is Urban Dictionary if the chat has switched it off
+/// and any source whose command doesn't fit into the callback data.
+/// No row is added if there are no buttons left.
+///
+/// # Examples
+///
+/// ```
+/// let keyboard = with_sources_row(
+///     origin_keyboard("run"),
+///     "run",
+///     ResultSource::Dictionary,
+///     &ChatSettings::default(),
+/// );
+/// assert_eq!(keyboard.inline_keyboard[1][0].text, "Thesaurus");
+/// ```
+pub fn with_sources_row(
+    keyboard: InlineKeyboardMarkup,
+    term: &str,
+    current: ResultSource,
+    settings: &ChatSettings,
+) -> InlineKeyboardMarkup {
+    let buttons = ResultSource::ALL
+        .into_iter()
+        .filter(|source| *source != current)
+        .filter(|source| *source != ResultSource::Urban || settings.urban_allowed)
+        .filter_map(|source| {
+            source
+                .command(term)
+                .and_then(|command| command.to_data())
+                .map(|data| InlineKeyboardButton::callback(source.name(), data))
+        })
+        .collect::<Vec<_>>();
+    match buttons.is_empty() {
+        true => keyboard,
+        false => keyboard.append_row(buttons),
+    }
+}
+
+/// Builds a keyboard with just the row of buttons switching the result to another source,
+/// see [`with_sources_row`].
+pub fn sources_keyboard(
+    term: &str,
+    current: ResultSource,
+    settings: &ChatSettings,
+) -> InlineKeyboardMarkup {
+    with_sources_row(InlineKeyboardMarkup::default(), term, current, settings)
+}
+
+/// Builds the `/settings` menu: a button per setting, labeled with its current value,
+/// which moves the setting on to its next value when pressed.
+///
+/// # Examples
+///
+/// ```
+/// let keyboard = settings_keyboard(&ChatSettings::default());
+/// assert_eq!(keyboard.inline_keyboard[0][0].text, "Max results: 5");
+/// ```
+pub fn settings_keyboard(settings: &ChatSettings) -> InlineKeyboardMarkup {
+    let buttons = Setting::ALL.into_iter().filter_map(|setting| {
+        CallbackCommands::Settings(setting).to_data().map(|data| {
+            [InlineKeyboardButton::callback(
+                settings.describe(setting),
+                data,
+            )]
+        })
+    });
+    InlineKeyboardMarkup::new(buttons)
+}
+
+fn single_button_keyboard(text: &str, command: CallbackCommands) -> InlineKeyboardMarkup {
+    match command.to_data() {
+        Some(data) => InlineKeyboardMarkup::new([[InlineKeyboardButton::callback(text, data)]]),
+        None => InlineKeyboardMarkup::default(),
+    }
+}
+
+/// Acknowledges the button press, so that the user's client stops showing it as pending.
+///
+/// Telegram accepts a single answer per callback query, so it's answered here, before dispatching,
+/// rather than by the handlers, which may respond more than once, e.g. when falling back to an error.
+/// A failure to answer is logged and doesn't stop the handling.
+async fn acknowledge(bot: Bot, query: CallbackQuery) {
+    if let Err(err) = bot.answer_callback_query(query.id).await {
+        log::error!("Failed to answer callback query: {:?}", err);
+    }
+}
+
+/// Builds the handler tree for presses of inline keyboard buttons.
+///
+/// The tree parses the button's callback data into a `CallbackCommands`, acknowledges the press, wraps
+/// the query into a `CallbackBot` and dispatches to the matching bloc handler. Queries with unknown data are ignored.
+///
+/// # Examples
+///
+/// ```
+/// let handler = callbacks_tree();
+/// // Mount `handler` into a teloxide dispatcher dptree.
+/// ```
+pub fn callbacks_tree() -> CommandHandler {
+    Update::filter_callback_query()
+        .filter_map(|query: CallbackQuery| query.data.as_deref().and_then(CallbackCommands::parse))
+        .inspect_async(acknowledge)
+        .map(
+            |bot: Bot, query: CallbackQuery, settings: SettingsStore| CallbackBot {
+                settings: settings.get(CallbackBot::chat_id(&query)),
+                bot,
+                query,
+            },
+        )
+        .branch(
+            teloxide::dptree::case![CallbackCommands::WordLookup(word)]
+                .branch(CallbackBot::word_lookup_handler()),
+        )
+        .branch(
+            teloxide::dptree::case![CallbackCommands::PhraseLookup(phrase)]
+                .branch(CallbackBot::phrase_lookup_handler()),
+        )
+        .branch(
+            teloxide::dptree::case![CallbackCommands::Thesaurus(phrase)]
+                .branch(CallbackBot::thesaurus_lookup_handler()),
+        )
+        .branch(
+            teloxide::dptree::case![CallbackCommands::Urban(phrase)]
+                .branch(CallbackBot::urban_lookup_handler()),
+        )
+        .branch(
+            teloxide::dptree::case![CallbackCommands::Etymology(word)]
+                .branch(CallbackBot::etymology_handler()),
+        )
+        .branch(
+            teloxide::dptree::case![CallbackCommands::Abbreviations(query)]
+                .branch(CallbackBot::abbr_lookup_handler()),
+        )
+        .branch(
+            teloxide::dptree::case![CallbackCommands::Say(word)]
+                .branch(CallbackBot::pronunciation_handler()),
+        )
+        .branch(
+            teloxide::dptree::case![CallbackCommands::Settings(setting)]
+                .branch(CallbackBot::change_setting_handler()),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn word_lookup_data_round_trips() {
+        let command = CallbackCommands::WordLookup("receive".to_string());
+        let data = command.to_data().unwrap();
+        assert_eq!(data, "w:receive");
+        assert_eq!(CallbackCommands::parse(&data), Some(command));
+    }
+
+    #[test]
+    fn etymology_data_round_trips() {
+        let command = CallbackCommands::Etymology("run".to_string());
+        let data = command.to_data().unwrap();
+        assert_eq!(data, "o:run");
+        assert_eq!(CallbackCommands::parse(&data), Some(command));
+    }
+
+    #[test]
+    fn say_data_round_trips() {
+        let command = CallbackCommands::Say("serendipity".to_string());
+        let data = command.to_data().unwrap();
+        assert_eq!(data, "v:serendipity");
+        assert_eq!(CallbackCommands::parse(&data), Some(command));
+    }
+
+    #[test]
+    fn abbreviations_data_round_trips() {
+        let command = CallbackCommands::Abbreviations("asap computing".to_string());
+        let data = command.to_data().unwrap();
+        assert_eq!(data, "a:asap computing");
+        assert_eq!(CallbackCommands::parse(&data), Some(command));
+    }
+
+    #[test]
+    fn settings_data_round_trips() {
+        for setting in Setting::ALL {
+            let command = CallbackCommands::Settings(setting);
+            let data = command.to_data().unwrap();
+            assert_eq!(CallbackCommands::parse(&data), Some(command));
+        }
+        assert_eq!(CallbackCommands::parse("s:unknown"), None);
+    }
+
+    #[test]
+    fn source_data_round_trips() {
+        for command in [
+            CallbackCommands::PhraseLookup("buckle up".to_string()),
+            CallbackCommands::Thesaurus("cool down".to_string()),
+            CallbackCommands::Urban("yeet".to_string()),
+        ] {
+            let data = command.to_data().unwrap();
+            assert_eq!(CallbackCommands::parse(&data), Some(command));
+        }
+    }
+
+    #[test]
+    fn sources_row_leaves_out_unavailable_sources() {
+        let row = |term: &str, current: ResultSource, settings: &ChatSettings| {
+            sources_keyboard(term, current, settings).inline_keyboard[0]
+                .iter()
+                .map(|button| button.text.clone())
+                .collect::<Vec<_>>()
+        };
+        let settings = ChatSettings::default();
+        assert_eq!(
+            row("run", ResultSource::Dictionary, &settings),
+            vec!["Thesaurus", "Urban", "Abbreviations"]
+        );
+        assert_eq!(
+            row("buckle up", ResultSource::Urban, &settings),
+            vec!["Dictionary", "Thesaurus"]
+        );
+        let settings = ChatSettings {
+            urban_allowed: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            row("run", ResultSource::Thesaurus, &settings),
+            vec!["Dictionary", "Abbreviations"]
+        );
+    }
+
+    #[test]
+    fn categories_keyboard_has_two_buttons_a_row() {
+        let categories = [("Computing", 2), ("Business", 1), ("Other", 5)];
+        let keyboard = abbreviation_categories_keyboard("asap", &categories);
+        assert_eq!(keyboard.inline_keyboard.len(), 2);
+        assert_eq!(keyboard.inline_keyboard[0][0].text, "Computing (2)");
+        assert_eq!(keyboard.inline_keyboard[1][0].text, "Other (5)");
+    }
+
+    #[test]
+    fn too_long_data_is_rejected() {
+        let command = CallbackCommands::WordLookup("a".repeat(MAX_DATA_LEN));
+        assert_eq!(command.to_data(), None);
+    }
+
+    #[test]
+    fn unknown_data_is_ignored() {
+        assert_eq!(CallbackCommands::parse("x:receive"), None);
+        assert_eq!(CallbackCommands::parse("w:"), None);
+        assert_eq!(CallbackCommands::parse("receive"), None);
+    }
+
+    #[test]
+    fn keyboard_skips_words_not_fitting_data() {
+        let words = vec!["receive".to_string(), "a".repeat(MAX_DATA_LEN)];
+        let keyboard = word_lookup_keyboard(&words);
+        assert_eq!(keyboard.inline_keyboard.len(), 1);
+        assert_eq!(keyboard.inline_keyboard[0].len(), 1);
+        assert_eq!(keyboard.inline_keyboard[0][0].text, "receive");
+    }
+
+    #[test]
+    fn origin_keyboard_is_empty_for_words_not_fitting_data() {
+        assert_eq!(origin_keyboard("run").inline_keyboard.len(), 1);
+        assert!(
+            origin_keyboard(&"a".repeat(MAX_DATA_LEN))
+                .inline_keyboard
+                .is_empty()
+        );
+    }
+}
@@ -1,22 +1,36 @@
+use crate::bloc::abbr_lookup::AbbrLookupHandler;
+use crate::bloc::all_lookup::AllLookupHandler;
+use crate::bloc::auto_lookup::AutoLookupHandler;
 use crate::bloc::common::CommandHandler;
+use crate::bloc::etymology::EtymologyHandler;
 use crate::bloc::help::HelpHandler;
+use crate::bloc::pattern_finder::PatternFinderHandler;
 use crate::bloc::phrase_lookup::PhraseLookupHandler;
+use crate::bloc::pronunciation::PronunciationHandler;
+use crate::bloc::quote_lookup::QuoteLookupHandler;
+use crate::bloc::reverse_lookup::ReverseLookupHandler;
+use crate::bloc::settings::SettingsHandler;
 use crate::bloc::start::StartHandler;
 use crate::bloc::teapot::TeapotHandler;
 use crate::bloc::thesaurus_lookup::ThesaurusLookupHandler;
 use crate::bloc::unknown::UnknownHandler;
 use crate::bloc::urban_lookup::UrbanLookupHandler;
-use crate::bloc::word_finder::WordFinderHandler;
+use crate::bloc::word_finder::{WordFinderHandler, looks_like_mask};
 use crate::bloc::word_lookup::WordLookupHandler;
 use crate::bloc::wordle::WordleHandler;
-use crate::bot::MessageBot;
+use crate::bot::{LookupBotX, MessageBot, SentReplies};
+use crate::commands::reply::{ReplyFallback, reply_fallback};
+use crate::format::ToEscaped;
+use crate::settings::{ChatSettings, LookupSource, SettingsStore};
+use crate::text::normalize;
 use teloxide::Bot;
 use teloxide::dispatching::UpdateFilterExt;
+use teloxide::dptree;
 use teloxide::prelude::{Message, Update};
-use teloxide::types::Me;
+use teloxide::types::{InlineKeyboardMarkup, Me};
 use teloxide::utils::command::{BotCommands, ParseError};
 
-#[derive(Clone, BotCommands, Debug)]
+#[derive(Clone, BotCommands, Debug, PartialEq)]
 #[command(
     rename_rule = "lowercase",
     description = "Here are the supported commands:"
@@ -34,7 +48,9 @@ pub enum MessageCommands {
         rename = "word",
         description = "Find definition of the specified word.\n\
         Any message containing at most 1 word, even with hyphens, will be looked up.\n\
-        Also you can request to look up a word in any chat by writing `@WordsLookupBot look`"
+        Add a part of speech to get only its definitions, like `/word run verb`.\n\
+        Also you can request to look up a word in any chat by writing `@WordsLookupBot look`, \
+        or `@WordsLookupBot run:v` for a part of speech"
     )]
     WordLookup(String),
     #[command(
@@ -61,17 +77,67 @@ pub enum MessageCommands {
     Thesaurus(String),
     #[command(
         description = "Get a list of words that have characters at specified positions.\n\
-        For example, `___ly` will return all 5-letter words that end with `ly`.\
+        For example, `___ly` will return all 5-letter words that end with `ly`.\n\
+        Besides `_` for any letter, a mask may use `*` for any number of letters, `#` for a consonant, \
+        `@` for a vowel, or `[aeiou]` for one of the listed letters, like `un*[ai]ble`.\
         Also you can request to look up a word in any chat by writing `@WordsLookupBot f.___ly`, \
         where `f.` will point try match words against the specified mask.\
         Furthermore, you can specify a list of letters to exclude from being used in a word, \
-        just add a comma and a continuous string, like a `wqg`"
+        just add a comma and a continuous string, like a `wqg`.\n\
+        The most common words are listed first, add `sort=alpha` to list them alphabetically \
+        or `min_freq=N` to skip words used less than N times per million words"
     )]
     Finder(String),
+    #[command(
+        description = "Find words for a crossword by a mask and a clue, separated with a comma.\n\
+        For example, `/clue c_t, feline pet` will suggest `cat`, the best matches for the clue go first.\
+        The mask supports the same wildcards as `/finder`. \
+        Also you can request it in any chat by writing `@WordsLookupBot c.c_t, feline pet`"
+    )]
+    Clue(String),
+    #[command(
+        description = "Find words by the way their letters repeat, e.g. to solve a cryptogram.\n\
+        For example, `/pattern abca` will return words like `that` or `area`, \
+        where the first and the last letters are the same, while the rest are different.\
+        Add a length after a comma to find longer words with the pattern anywhere in them, \
+        the rest of their letters being any, like `/pattern abab, 6` returning `banana`"
+    )]
+    Pattern(String),
+    #[command(
+        description = "Find words matching a description, like a reverse dictionary.\n\
+        For example, `/reverse a person who hoards books` will suggest words like `bibliophile`, \
+        the best matches are accompanied with their definitions"
+    )]
+    Reverse(String),
+    #[command(description = "Hear how a word is pronounced.\n\
+        For example, `/say colonel` will send a recording of the word along with its IPA transcription")]
+    Say(String),
+    #[command(description = "Find out where a word comes from.\n\
+        For example, `/etym salary` will trace the word back through the languages it came from")]
+    Etym(String),
+    #[command(description = "Find quotes by a keyword or an author.\n\
+        For example, `/quote courage` or `/quote mark twain`")]
+    Quote(String),
+    #[command(description = "Look up what an abbreviation stands for.\n\
+        For example, `/abbr asap` lists its categories, `/abbr asap business` shows the definitions of one of them")]
+    Abbr(String),
+    #[command(description = "Look a term up in every source at once.\n\
+        For example, `/all cookie` shows its definitions, abbreviations, thesaurus entries and Urban Dictionary meanings")]
+    All(String),
+    #[command(
+        rename = "autolookup",
+        description = "In a group, switch looking up plain single-word messages on or off.\n\
+        Otherwise the bot only answers commands, messages mentioning it and replies to it. \
+        Use `/autolookup on` or `/autolookup off`, or just `/autolookup` to flip it"
+    )]
+    AutoLookup(String),
+    #[command(description = "Change the preferences of this chat, \
+        like the number of results, the output style or where plain text is looked up")]
+    Settings,
 }
 /// Convert plain text into a MessageCommands value based on content and word count.
 ///
-/// If the input contains any underscore character, returns `Finder` with the original text.
+/// If the input looks like a mask, i.e. contains `_` or `*`, returns `Finder` with the original text.
 /// Otherwise splits the input on whitespace, lowercases tokens, and maps:
 /// - empty input -> `Teapot`
 /// - single token -> `WordLookup` with the lowercase token
@@ -85,10 +151,12 @@ pub enum MessageCommands {
 /// assert_eq!(extract_text_command("f__nd_me"), MessageCommands::Finder("f__nd_me".into()));
 /// // underscore anywhere in the input takes the Finder path, even with spaces
 /// assert_eq!(extract_text_command("a_b c"), MessageCommands::Finder("a_b c".into()));
+/// // `#` and `@` alone don't, they are rather hashtags and mentions
+/// assert_eq!(extract_text_command("#tbt"), MessageCommands::WordLookup("#tbt".into()));
 /// assert_eq!(extract_text_command("Hello WORLD"), MessageCommands::PhraseLookup("hello world".into()));
 /// ```
 fn extract_text_command(text: &str) -> MessageCommands {
-    if text.contains("_") {
+    if looks_like_mask(text) {
         return MessageCommands::Finder(text.to_owned());
     }
 
@@ -102,28 +170,191 @@ fn extract_text_command(text: &str) -> MessageCommands {
         _ => MessageCommands::PhraseLookup(words.join(" ")),
     }
 }
-/// Resolve a Telegram `Message` into a `MessageCommands` value using the bot's identity.
+
+/// Convert plain text into a MessageCommands value like [`extract_text_command`] does, but looking up
+/// the words and phrases in the chat's preferred source instead of the dictionary.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(
+///     extract_source_command("yeet", LookupSource::Urban),
+///     MessageCommands::Urban("yeet".into())
+/// );
+/// ```
+fn extract_source_command(text: &str, source: LookupSource) -> MessageCommands {
+    match (extract_text_command(text), source) {
+        (
+            MessageCommands::WordLookup(term) | MessageCommands::PhraseLookup(term),
+            LookupSource::Thesaurus,
+        ) => MessageCommands::Thesaurus(term),
+        (
+            MessageCommands::WordLookup(term) | MessageCommands::PhraseLookup(term),
+            LookupSource::Urban,
+        ) => MessageCommands::Urban(term),
+        (
+            MessageCommands::WordLookup(term) | MessageCommands::PhraseLookup(term),
+            LookupSource::All,
+        ) => MessageCommands::All(term),
+        (cmd, _) => cmd,
+    }
+}
+
+/// Resolve the text of a private message into a `MessageCommands` value, every such message being addressed to the bot.
 ///
-/// Attempts to parse the message text as a bot command (taking the bot username into account).
+/// The text is parsed as a bot command, taking the bot's `username` into account.
 /// If parsing yields an unknown slash command (one that starts with `/`) the function returns
-/// `MessageCommands::Unknown`. For other parse failures the function falls back to text-based
-/// extraction: input containing an underscore becomes `MessageCommands::Finder`, a single word
-/// becomes `MessageCommands::WordLookup`, and multiple words become `MessageCommands::PhraseLookup`.
+/// `MessageCommands::Unknown`. For other parse failures the text is looked up in the chat's preferred
+/// `source`, see [`extract_source_command`].
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(
+///     extract_private_command("/word yeet", "WordsLookupBot", LookupSource::Urban),
+///     MessageCommands::WordLookup("yeet".into())
+/// );
+/// assert_eq!(
+///     extract_private_command("yeet", "WordsLookupBot", LookupSource::Urban),
+///     MessageCommands::Urban("yeet".into())
+/// );
+/// ```
+fn extract_private_command(text: &str, username: &str, source: LookupSource) -> MessageCommands {
+    MessageCommands::parse(text, username).unwrap_or_else(|err| match err {
+        ParseError::UnknownCommand(cmd) if cmd.starts_with("/") => MessageCommands::Unknown,
+        _ => extract_source_command(text, source),
+    })
+}
+
+/// Resolve the text of a group message into a `MessageCommands` value, if the bot is meant to act on it.
+///
+/// Removes the bot's `mention`, like `@wordslookupbot`, from the lowercased text.
+///
+/// Only whole mentions count, so that a longer username like `@wordslookupbotfan` isn't taken for the bot's.
+///
+/// # Returns
+///
+/// `Some(String)` with the lowercased text without the mentions, or `None` if the bot isn't mentioned.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(strip_mention("@WordsLookupBot hello", "@wordslookupbot"), Some(" hello".to_string()));
+/// assert_eq!(strip_mention("@wordslookupbotfan hello", "@wordslookupbot"), None);
+/// ```
+fn strip_mention(text: &str, mention: &str) -> Option<String> {
+    let mut text = text.to_lowercase();
+    let is_name = |c: char| c.is_alphanumeric() || c == '_';
+    let whole = text
+        .match_indices(mention)
+        .map(|(i, _)| i)
+        .filter(|&i| {
+            !text[..i].ends_with(is_name) && !text[i + mention.len()..].starts_with(is_name)
+        })
+        .collect::<Vec<_>>();
+    if whole.is_empty() {
+        return None;
+    }
+    for i in whole.into_iter().rev() {
+        text.replace_range(i..i + mention.len(), " ");
+    }
+    Some(text)
+}
+
+/// Unlike in private chats, not every message is addressed to the bot, so only these are acted on:
+/// - explicit commands; an unknown one only if it names the bot, e.g. `/foo@WordsLookupBot`,
+///   since it might belong to another bot otherwise,
+/// - messages mentioning the bot, with the mention itself left out of the lookup,
+/// - replies to the bot's own messages,
+/// - single words, if the chat has switched `auto_lookup` on.
+///
+/// Plain text is looked up in the chat's `default_source`.
+///
+/// # Returns
+///
+/// `Some(MessageCommands)` to act on, `None` for a message to be ignored.
+///
+/// # Examples
+///
+/// ```
+/// let settings = ChatSettings::default();
+/// assert_eq!(extract_group_command("hello", "WordsLookupBot", false, &settings), None);
+/// assert_eq!(
+///     extract_group_command("@wordslookupbot hello", "WordsLookupBot", false, &settings),
+///     Some(MessageCommands::WordLookup("hello".into()))
+/// );
+/// ```
+fn extract_group_command(
+    text: &str,
+    username: &str,
+    replies_to_bot: bool,
+    settings: &ChatSettings,
+) -> Option<MessageCommands> {
+    let mention = format!("@{}", username.to_lowercase());
+    if text.starts_with('/') {
+        let addressed = text
+            .split_whitespace()
+            .next()
+            .is_some_and(|cmd| cmd.to_lowercase().ends_with(&mention));
+        return match MessageCommands::parse(text, username) {
+            Ok(cmd) => Some(cmd),
+            Err(ParseError::UnknownCommand(_)) if addressed => Some(MessageCommands::Unknown),
+            Err(_) => None,
+        };
+    }
+    if let Some(text) = strip_mention(text, &mention) {
+        return Some(extract_source_command(&text, settings.default_source));
+    }
+    if replies_to_bot {
+        return Some(extract_source_command(text, settings.default_source));
+    }
+    match extract_text_command(text) {
+        MessageCommands::WordLookup(_) if settings.auto_lookup => {
+            Some(extract_source_command(text, settings.default_source))
+        }
+        _ => None,
+    }
+}
+
+/// Resolve a Telegram `Message` into a `MessageCommands` value using the bot's identity and the chat's settings.
+///
+/// The text is normalized the same way as inline queries (see [`normalize`]). In a private chat every
+/// message is acted on: it is parsed as a bot command (taking the bot username into account), an
+/// unknown slash command (one that starts with `/`) becomes `MessageCommands::Unknown`, and other
+/// parse failures fall back to text-based extraction: input looking like a mask becomes
+/// `MessageCommands::Finder`, a single word becomes `MessageCommands::WordLookup`, and multiple words
+/// become `MessageCommands::PhraseLookup`. In a group only the messages addressed to the bot are acted on,
+/// see [`extract_group_command`].
+///
+/// # Returns
+///
+/// `Some(MessageCommands)` to act on, `None` for a message to be ignored.
 ///
 /// # Examples
 ///
 /// ```
 /// // Construct appropriate `Message` and `Me` values in your test harness and call:
-/// // let cmd = extract_command(message, me);
-/// // assert!(matches!(cmd, MessageCommands::WordLookup(_) | MessageCommands::PhraseLookup(_) | MessageCommands::Unknown));
+/// // let cmd = extract_command(message, me, settings);
+/// // assert!(matches!(cmd, Some(MessageCommands::WordLookup(_) | MessageCommands::PhraseLookup(_)) | None));
 /// ```
-fn extract_command(message: Message, me: Me) -> MessageCommands {
-    let text = message.text().unwrap_or_default().to_lowercase();
+fn extract_command(message: Message, me: Me, settings: SettingsStore) -> Option<MessageCommands> {
+    let text = normalize(message.text().unwrap_or_default());
     let username = me.username.clone().unwrap_or_default();
-    let cmd = MessageCommands::parse(text.as_str(), &username).unwrap_or_else(|err| match err {
-        ParseError::UnknownCommand(cmd) if cmd.starts_with("/") => MessageCommands::Unknown,
-        _ => extract_text_command(text.as_str()),
-    });
+    let settings = settings.get(message.chat.id);
+    let cmd = match message.chat.is_private() {
+        true => Some(extract_private_command(
+            &text,
+            &username,
+            settings.default_source,
+        )),
+        false => {
+            let replies_to_bot = message
+                .reply_to_message()
+                .and_then(|reply| reply.from.as_ref())
+                .is_some_and(|user| user.id == me.id);
+            extract_group_command(&text, &username, replies_to_bot, &settings)
+        }
+    };
 
     log::info!("Received message: {:?}", text);
     log::info!("Processing command {:?}", cmd);
@@ -132,7 +363,7 @@ fn extract_command(message: Message, me: Me) -> MessageCommands {
 
 /// Builds the update dispatch tree that routes incoming message updates to their command handlers.
 ///
-/// The handler filters for message updates, converts each message into a `MessageCommands` value,
+/// The handler filters for new and edited message updates, converts each message into a `MessageCommands` value,
 /// wraps the bot and message into a `MessageBot`, and dispatches to the matching handler branch
 /// (Finder, Wordle, WordLookup, PhraseLookup, Urban, Thesaurus, Help, Unknown, Start, Teapot).
 ///
@@ -143,16 +374,67 @@ fn extract_command(message: Message, me: Me) -> MessageCommands {
 /// // Attach `handler` to a teloxide dispatcher to process incoming updates.
 /// ```
 pub fn commands_tree() -> CommandHandler {
-    Update::filter_message()
-        .map(extract_command)
+    // edited messages are answered too, so that a fixed typo gets its lookup re-run
+    dptree::entry()
+        .branch(Update::filter_message().chain(message_tree()))
+        .branch(Update::filter_edited_message().chain(message_tree()))
+}
+
+/// Builds the part of the tree shared by new and edited messages, from the message to its command handler.
+fn message_tree() -> CommandHandler {
+    dptree::filter_map(extract_command)
         .inspect(|message: Message| {
             log::debug!("Answering chat {:?}", message.chat.id);
         })
-        .map(|bot: Bot, message: Message| MessageBot { bot, message })
+        .map(
+            |bot: Bot, message: Message, settings: SettingsStore, replies: SentReplies| {
+                MessageBot {
+                    settings: settings.get(message.chat.id),
+                    reply_to: None,
+                    replies,
+                    bot,
+                    message,
+                }
+            },
+        )
+        .branch(reply_fallback_tree())
+        .branch(command_branches())
+}
+
+/// Builds the branch answering empty-argument commands that reply to another message with that message's text.
+///
+/// The responses are threaded to the replied message. When the command takes a single word and the message
+/// has several, the user is asked which one with buttons instead.
+fn reply_fallback_tree() -> CommandHandler {
+    dptree::filter_map(reply_fallback)
+        .map(|bot: MessageBot, message: Message| MessageBot {
+            reply_to: message.reply_to_message().map(|replied| replied.id),
+            ..bot
+        })
+        .branch(dptree::case![ReplyFallback::Pick(keyboard)].endpoint(
+            |bot: MessageBot, keyboard: InlineKeyboardMarkup| async move {
+                let text = "Which word do you mean?".to_string().to_escaped();
+                bot.respond_with_keyboard(text, keyboard).await
+            },
+        ))
+        .branch(dptree::case![ReplyFallback::Run(cmd)].branch(command_branches()))
+}
+
+/// Builds the branches dispatching each `MessageCommands` value to its handler.
+fn command_branches() -> CommandHandler {
+    dptree::entry()
         .branch(
             teloxide::dptree::case![MessageCommands::Finder(mask)]
                 .branch(MessageBot::word_finder_handler()),
         )
+        .branch(
+            teloxide::dptree::case![MessageCommands::Clue(clue)]
+                .branch(MessageBot::clue_finder_handler()),
+        )
+        .branch(
+            teloxide::dptree::case![MessageCommands::Pattern(pattern)]
+                .branch(MessageBot::pattern_finder_handler()),
+        )
         .branch(
             teloxide::dptree::case![MessageCommands::Wordle].branch(MessageBot::wordle_handler()),
         )
@@ -172,6 +454,38 @@ pub fn commands_tree() -> CommandHandler {
             teloxide::dptree::case![MessageCommands::Thesaurus(word)]
                 .branch(MessageBot::thesaurus_lookup_handler()),
         )
+        .branch(
+            teloxide::dptree::case![MessageCommands::Reverse(description)]
+                .branch(MessageBot::reverse_lookup_handler()),
+        )
+        .branch(
+            teloxide::dptree::case![MessageCommands::Say(word)]
+                .branch(MessageBot::pronunciation_handler()),
+        )
+        .branch(
+            teloxide::dptree::case![MessageCommands::Etym(word)]
+                .branch(MessageBot::etymology_handler()),
+        )
+        .branch(
+            teloxide::dptree::case![MessageCommands::Quote(query)]
+                .branch(MessageBot::quote_lookup_handler()),
+        )
+        .branch(
+            teloxide::dptree::case![MessageCommands::Abbr(query)]
+                .branch(MessageBot::abbr_lookup_handler()),
+        )
+        .branch(
+            teloxide::dptree::case![MessageCommands::All(query)]
+                .branch(MessageBot::all_lookup_handler()),
+        )
+        .branch(
+            teloxide::dptree::case![MessageCommands::AutoLookup(arg)]
+                .branch(MessageBot::auto_lookup_handler()),
+        )
+        .branch(
+            teloxide::dptree::case![MessageCommands::Settings]
+                .branch(MessageBot::settings_handler()),
+        )
         .branch(teloxide::dptree::case![MessageCommands::Help].branch(MessageBot::help_handler()))
         .branch(
             teloxide::dptree::case![MessageCommands::Unknown].branch(MessageBot::unknown_handler()),
@@ -180,4 +494,111 @@ pub fn commands_tree() -> CommandHandler {
         .branch(
             teloxide::dptree::case![MessageCommands::Teapot].branch(MessageBot::teapot_handler()),
         )
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USERNAME: &str = "WordsLookupBot";
+
+    fn auto_lookup() -> ChatSettings {
+        ChatSettings {
+            auto_lookup: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn group_ignores_plain_messages() {
+        assert_eq!(extract_group_command("hello", USERNAME, false, &ChatSettings::default()), None);
+        assert_eq!(
+            extract_group_command("hello there", USERNAME, false, &auto_lookup()),
+            None
+        );
+        assert_eq!(extract_group_command("/foo", USERNAME, false, &ChatSettings::default()), None);
+    }
+
+    #[test]
+    fn group_acts_on_messages_addressed_to_bot() {
+        assert_eq!(
+            extract_group_command("/urban yeet", USERNAME, false, &ChatSettings::default()),
+            Some(MessageCommands::Urban("yeet".to_string()))
+        );
+        assert_eq!(
+            extract_group_command("/foo@wordslookupbot", USERNAME, false, &ChatSettings::default()),
+            Some(MessageCommands::Unknown)
+        );
+        assert_eq!(
+            extract_group_command("@wordslookupbot look up", USERNAME, false, &ChatSettings::default()),
+            Some(MessageCommands::PhraseLookup("look up".to_string()))
+        );
+        assert_eq!(
+            extract_group_command("hello there", USERNAME, true, &ChatSettings::default()),
+            Some(MessageCommands::PhraseLookup("hello there".to_string()))
+        );
+        assert_eq!(
+            extract_group_command("hello", USERNAME, false, &auto_lookup()),
+            Some(MessageCommands::WordLookup("hello".to_string()))
+        );
+    }
+
+    #[test]
+    fn group_ignores_longer_usernames() {
+        assert_eq!(
+            extract_group_command("@wordslookupbotfan hello", USERNAME, false, &ChatSettings::default()),
+            None
+        );
+        assert_eq!(
+            extract_group_command("@wordslookupbot_fan hello", USERNAME, false, &ChatSettings::default()),
+            None
+        );
+        assert_eq!(
+            extract_group_command("look up @WordsLookupBot", USERNAME, false, &ChatSettings::default()),
+            Some(MessageCommands::PhraseLookup("look up".to_string()))
+        );
+        assert_eq!(
+            strip_mention("@WordsLookupBot, hi", "@wordslookupbot"),
+            Some(" , hi".to_string())
+        );
+    }
+
+    #[test]
+    fn plain_text_is_looked_up_in_default_source() {
+        let settings = ChatSettings {
+            default_source: LookupSource::Urban,
+            ..auto_lookup()
+        };
+        assert_eq!(
+            extract_group_command("yeet", USERNAME, false, &settings),
+            Some(MessageCommands::Urban("yeet".to_string()))
+        );
+        assert_eq!(
+            extract_private_command("/word yeet", USERNAME, LookupSource::Urban),
+            MessageCommands::WordLookup("yeet".to_string())
+        );
+        assert_eq!(
+            extract_private_command("f_nd", USERNAME, LookupSource::All),
+            MessageCommands::Finder("f_nd".to_string())
+        );
+    }
+
+    #[test]
+    fn mask_symbols_alone_are_looked_up() {
+        assert_eq!(
+            extract_text_command("john@example.com"),
+            MessageCommands::WordLookup("john@example.com".to_string())
+        );
+        assert_eq!(
+            extract_text_command("ask @alice"),
+            MessageCommands::PhraseLookup("ask @alice".to_string())
+        );
+        assert_eq!(
+            extract_text_command("#throwback"),
+            MessageCommands::WordLookup("#throwback".to_string())
+        );
+        assert_eq!(
+            extract_text_command("c[au]#_"),
+            MessageCommands::Finder("c[au]#_".to_string())
+        );
+    }
+}
@@ -1,9 +1,10 @@
 use crate::bloc::formatting::SynAntFormatterExt;
 use crate::format::{ToEscaped, as_in, meaning};
+use crate::settings::ChatSettings;
 use crate::{
     format::{LinksProvider, LookupFormatter, StringBuilderExt},
     stands4::SynAntDefinitions,
-    stands4::entities::{AbbreviationDefinition, PhraseDefinition, WordDefinition},
+    stands4::entities::{AbbreviationDefinition, PhraseDefinition, QuoteDefinition, WordDefinition},
     urban::UrbanDefinition,
 };
 use std::ops::Not;
@@ -12,6 +13,14 @@ use std::ops::Not;
 pub struct FullMessageFormatter {
     builder: string_builder::Builder,
     link_provider: LinksProvider,
+    settings: ChatSettings,
+}
+
+impl FullMessageFormatter {
+    /// How long the message composed so far is, in bytes.
+    pub(crate) fn len(&self) -> usize {
+        self.builder.len()
+    }
 }
 
 impl LookupFormatter for FullMessageFormatter {
@@ -46,10 +55,29 @@ impl LookupFormatter for FullMessageFormatter {
         &self.link_provider
     }
 
+    /// Access the settings of the chat the response is composed for.
+    fn settings(&self) -> &ChatSettings {
+        &self.settings
+    }
+
+    /// Composes the response according to the chat's settings, e.g. leaving the examples out
+    /// when the chat prefers a compact output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let settings = ChatSettings { compact: true, ..Default::default() };
+    /// let fmt = FullMessageFormatter::default().with_settings(settings);
+    /// assert!(fmt.settings().compact);
+    /// ```
+    fn with_settings(self, settings: ChatSettings) -> Self {
+        FullMessageFormatter { settings, ..self }
+    }
+
     /// Appends a formatted word entry (index, term, part of speech, meaning, and optional example) to the formatter's internal builder.
     ///
     /// The appended entry includes the 1-based index, the escaped term, the part of speech (or `"?"` if empty),
-    /// the formatted meaning on its own line, and an optional formatted example if present. A blank line is appended after each entry.
+    /// the formatted meaning on its own line, and an optional formatted example if present, unless the chat prefers a compact output. A blank line is appended after each entry.
     ///
     /// # Examples
     ///
@@ -79,7 +107,7 @@ impl LookupFormatter for FullMessageFormatter {
             part_of_speech
         ));
         self.builder.appendl(meaning(&def.definition));
-        if def.example.is_empty().not() {
+        if def.example.is_empty().not() && self.settings.compact.not() {
             self.builder.appendl(as_in(&def.example));
         }
         self.builder.append("\n");
@@ -112,7 +140,7 @@ impl LookupFormatter for FullMessageFormatter {
         self.builder
             .append(format!("\\#{} \\- {}\n", i + 1, def.term));
         self.builder.appendl(meaning(&def.explanation));
-        if def.example.is_empty().not() {
+        if def.example.is_empty().not() && self.settings.compact.not() {
             self.builder.appendl(as_in(&def.example));
         }
         self.builder.append("\n");
@@ -202,6 +230,31 @@ impl LookupFormatter for FullMessageFormatter {
         self.builder.append("\n");
     }
 
+    /// Appends a single line naming an abbreviation category and how many definitions it holds,
+    /// leaving the definitions themselves out.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// let mut fmt = FullMessageFormatter::default();
+    /// let def = AbbreviationDefinition { definition: "As Soon As Possible".into(), category: "Other".into() };
+    /// fmt.visit_abbreviation_category(0, "Other", &[&def]);
+    /// assert_eq!(fmt.build().unwrap(), "\\#1 in \\[Other\\] \\- 1\n");
+    /// ```
+    fn visit_abbreviation_category(
+        &mut self,
+        i: usize,
+        category: &str,
+        defs: &[&AbbreviationDefinition],
+    ) {
+        self.builder.append(format!(
+            "\\#{} in \\[{}\\] \\- {}\n",
+            i + 1,
+            category.to_string().to_escaped(),
+            defs.len()
+        ));
+    }
+
     /// Appends a formatted synonym/antonym entry for a definition into the internal builder.
     ///
     /// The entry consists of a numbered header with the escaped term, the formatted meaning,
@@ -269,7 +322,7 @@ impl LookupFormatter for FullMessageFormatter {
         self.builder
             .append(format!("\\#{} \\- {}\n", i + 1, def.word));
         self.builder.appendl(meaning(&def.meaning));
-        if let Some(example) = &def.example {
+        if let Some(example) = def.example.as_ref().filter(|_| self.settings.compact.not()) {
             self.builder.appendl(as_in(example));
         }
         self.builder.append("\n");
@@ -296,6 +349,164 @@ impl LookupFormatter for FullMessageFormatter {
         }
     }
 
+    /// Appends a reverse-dictionary match, followed by its meaning when a definition is known.
+    ///
+    /// The entry starts with the 1-based index and the escaped candidate word; if `def` is present
+    /// its part of speech is added to the header and its definition is rendered on the next line.
+    /// A blank line is appended after each entry.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// let mut fmt = FullMessageFormatter::default();
+    /// fmt.visit_reverse_match(0, "bibliophile", None);
+    /// let out = fmt.build().unwrap();
+    /// assert!(out.contains("#1 \\- bibliophile"));
+    /// ```
+    fn visit_reverse_match(&mut self, i: usize, word: &str, def: Option<&WordDefinition>) {
+        let word = word.to_string().to_escaped();
+        match def.map(|def| def.to_escaped()) {
+            Some(def) if def.part_of_speech.is_empty().not() => {
+                self.builder.append(format!(
+                    "\\#{} \\- {} \\({}\\)\n",
+                    i + 1,
+                    word,
+                    def.part_of_speech
+                ));
+                self.builder.appendl(meaning(&def.definition));
+            }
+            Some(def) => {
+                self.builder.append(format!("\\#{} \\- {}\n", i + 1, word));
+                self.builder.appendl(meaning(&def.definition));
+            }
+            None => {
+                self.builder.append(format!("\\#{} \\- {}\n", i + 1, word));
+            }
+        }
+        self.builder.append("\n");
+    }
+
+    /// Appends a notice that nothing was found for `word`, followed by the closest correctly spelled words.
+    ///
+    /// The searched word is put in bold; both it and the suggestions are escaped.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// let mut fmt = FullMessageFormatter::default();
+    /// fmt.visit_spelling_suggestions("recieve", &["receive".to_string(), "relieve".to_string()]);
+    /// let out = fmt.build().unwrap();
+    /// assert_eq!(out, "No results for *recieve*\\. Did you mean: receive, relieve?\n");
+    /// ```
+    fn visit_spelling_suggestions(&mut self, word: &str, suggestions: &[String]) {
+        self.builder.appendl(format!(
+            "No results for *{}*\\. Did you mean: {}?",
+            word.to_string().to_escaped(),
+            suggestions.to_vec().to_escaped().join(", ")
+        ));
+    }
+
+    /// Appends a note that the results are shown for the base form `lemma` of the looked up `word`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// let mut fmt = FullMessageFormatter::default();
+    /// fmt.visit_lemma("run", "running");
+    /// let out = fmt.build().unwrap();
+    /// assert_eq!(out, "Showing results for *run* \\(from *running*\\)\n\n");
+    /// ```
+    fn visit_lemma(&mut self, lemma: &str, word: &str) {
+        self.builder.append(format!(
+            "Showing results for *{}* \\(from *{}*\\)\n\n",
+            lemma.to_string().to_escaped(),
+            word.to_string().to_escaped()
+        ));
+    }
+
+    /// Appends a header with the word in bold followed by its IPA transcription between slashes.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// let mut fmt = FullMessageFormatter::default();
+    /// fmt.visit_pronunciation("hello", "həˈləʊ");
+    /// let out = fmt.build().unwrap();
+    /// assert_eq!(out, "*hello* /həˈləʊ/\n\n");
+    /// ```
+    fn visit_pronunciation(&mut self, word: &str, ipa: &str) {
+        self.builder.append(format!(
+            "*{}* /{}/\n\n",
+            word.to_string().to_escaped(),
+            ipa.to_string().to_escaped()
+        ));
+    }
+
+    /// Appends a numbered etymology entry: the index and the word, then the etymology on the following lines.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// let mut fmt = FullMessageFormatter::default();
+    /// fmt.visit_etymology(0, "run", "From Middle English rennen.");
+    /// let out = fmt.build().unwrap();
+    /// assert_eq!(out, "\\#1 \\- run\nFrom Middle English rennen\\.\n\n");
+    /// ```
+    fn visit_etymology(&mut self, i: usize, word: &str, etymology: &str) {
+        self.builder.append(format!(
+            "\\#{} \\- {}\n{}\n\n",
+            i + 1,
+            word.to_string().to_escaped(),
+            etymology.to_string().to_escaped()
+        ));
+    }
+
+    /// Appends a numbered quote in italics, followed by its author on a separate line if known.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// let mut fmt = FullMessageFormatter::default();
+    /// let def = QuoteDefinition {
+    ///     quote: "Fortune favors the bold.".into(),
+    ///     author: "Virgil".into(),
+    /// };
+    /// fmt.visit_quote(0, &def);
+    /// let out = fmt.build().unwrap();
+    /// assert_eq!(out, "\\#1 \\- _Fortune favors the bold\\._\n— Virgil\n\n");
+    /// ```
+    fn visit_quote(&mut self, i: usize, def: &QuoteDefinition) {
+        let def = def.to_escaped();
+        self.builder
+            .append(format!("\\#{} \\- _{}_\n", i + 1, def.quote));
+        if def.author.is_empty().not() {
+            self.builder.appendl(format!("— {}", def.author));
+        }
+        self.builder.append("\n");
+    }
+
+    /// Starts a section holding the entries of a single source, headed by the source's name in bold.
+    ///
+    /// An unavailable source gets a note in italics in place of its entries.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// let mut fmt = FullMessageFormatter::default();
+    /// fmt.visit_section("Urban Dictionary", false);
+    /// let out = fmt.build().unwrap();
+    /// assert_eq!(out, "*Urban Dictionary*\n_Source unavailable_\n\n");
+    /// ```
+    fn visit_section(&mut self, source: &str, available: bool) {
+        let source = source.to_string().to_escaped();
+        match available {
+            true => self.builder.append(format!("*{}*\n\n", source)),
+            false => self
+                .builder
+                .append(format!("*{}*\n_Source unavailable_\n\n", source)),
+        }
+    }
+
     /// Appends a title followed by two newline characters to the internal builder.
     ///
     /// The provided `title` is written as-is, then two newline characters (`\n\n`) are appended.
@@ -1,5 +1,6 @@
 mod command;
 mod formatting;
+mod reply;
 
 pub use command::*;
 pub use formatting::*;
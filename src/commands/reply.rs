@@ -0,0 +1,157 @@
+use crate::callbacks::{CallbackCommands, pick_keyboard};
+use crate::commands::MessageCommands;
+use crate::text::{WORD_CHARS, normalize};
+use regex::Regex;
+use std::sync::LazyLock;
+use teloxide::types::{InlineKeyboardMarkup, Message};
+
+static WORD_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!(r"[{WORD_CHARS}]+")).unwrap());
+
+/// How an empty-argument command is answered when it replies to another message.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReplyFallback {
+    /// The command is run on the text of the replied message.
+    Run(MessageCommands),
+    /// The command takes a single word, but the replied message has several,
+    /// so the user is asked to pick one of them.
+    Pick(InlineKeyboardMarkup),
+}
+
+/// Collects the distinct words of the text in the order they appear, leaving out punctuation.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(words_of("it's a rock-'n'-roll, rock!"), vec!["it's", "a", "rock-'n'-roll", "rock"]);
+/// ```
+fn words_of(text: &str) -> Vec<String> {
+    let mut words = Vec::<String>::new();
+    for word in WORD_PATTERN.find_iter(text) {
+        let word = word.as_str().trim_matches(['\'', '-']);
+        if !word.is_empty() && !words.iter().any(|known| known == word) {
+            words.push(word.to_string());
+        }
+    }
+    words
+}
+
+/// Decides how an empty-argument command is answered with the given text of the replied message.
+///
+/// Commands looking up a phrase get the whole text. Commands looking up a single word get it if the text
+/// is a single word, otherwise the user is asked to pick the word with buttons.
+///
+/// # Returns
+///
+/// `Some(ReplyFallback)`, or `None` if the command has an argument, doesn't take any, or the text has no words.
+///
+/// # Examples
+///
+/// ```
+/// let fallback = fallback_for(&MessageCommands::Urban(String::new()), "no cap");
+/// assert_eq!(fallback, Some(ReplyFallback::Run(MessageCommands::Urban("no cap".into()))));
+/// ```
+fn fallback_for(cmd: &MessageCommands, text: &str) -> Option<ReplyFallback> {
+    let words = words_of(&normalize(text));
+    if words.is_empty() {
+        return None;
+    }
+    let word_or_pick =
+        |run: fn(String) -> MessageCommands, pick: fn(String) -> CallbackCommands| match &words[..]
+        {
+            [word] => Some(ReplyFallback::Run(run(word.clone()))),
+            _ => Some(ReplyFallback::Pick(pick_keyboard(&words, pick))),
+        };
+    let phrase = words.join(" ");
+    match cmd {
+        MessageCommands::WordLookup(arg) if arg.trim().is_empty() => {
+            word_or_pick(MessageCommands::WordLookup, CallbackCommands::WordLookup)
+        }
+        MessageCommands::Etym(arg) if arg.trim().is_empty() => {
+            word_or_pick(MessageCommands::Etym, CallbackCommands::Etymology)
+        }
+        MessageCommands::Abbr(arg) if arg.trim().is_empty() => {
+            word_or_pick(MessageCommands::Abbr, CallbackCommands::Abbreviations)
+        }
+        MessageCommands::Say(arg) if arg.trim().is_empty() => {
+            word_or_pick(MessageCommands::Say, CallbackCommands::Say)
+        }
+        MessageCommands::PhraseLookup(arg) if arg.trim().is_empty() => {
+            Some(ReplyFallback::Run(MessageCommands::PhraseLookup(phrase)))
+        }
+        MessageCommands::Urban(arg) if arg.trim().is_empty() => {
+            Some(ReplyFallback::Run(MessageCommands::Urban(phrase)))
+        }
+        MessageCommands::Thesaurus(arg) if arg.trim().is_empty() => {
+            Some(ReplyFallback::Run(MessageCommands::Thesaurus(phrase)))
+        }
+        MessageCommands::All(arg) if arg.trim().is_empty() => {
+            Some(ReplyFallback::Run(MessageCommands::All(phrase)))
+        }
+        MessageCommands::Quote(arg) if arg.trim().is_empty() => {
+            Some(ReplyFallback::Run(MessageCommands::Quote(phrase)))
+        }
+        _ => None,
+    }
+}
+
+/// Decides how an empty-argument command is answered with the text, or the caption, of the message it replies to.
+///
+/// # Returns
+///
+/// `Some(ReplyFallback)` if the command should be answered with the replied message, `None` otherwise.
+pub fn reply_fallback(cmd: MessageCommands, message: Message) -> Option<ReplyFallback> {
+    let replied = message.reply_to_message()?;
+    let text = replied.text().or(replied.caption())?;
+    fallback_for(&cmd, text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn words_leave_out_punctuation_and_repetitions() {
+        assert_eq!(
+            words_of("it's a rock-'n'-roll, rock!"),
+            vec!["it's", "a", "rock-'n'-roll", "rock"]
+        );
+    }
+
+    #[test]
+    fn phrase_commands_take_the_whole_text() {
+        let fallback = fallback_for(&MessageCommands::Urban(String::new()), "No cap!");
+        assert_eq!(
+            fallback,
+            Some(ReplyFallback::Run(MessageCommands::Urban("no cap".into())))
+        );
+    }
+
+    #[test]
+    fn word_commands_ask_to_pick_among_several_words() {
+        let fallback = fallback_for(&MessageCommands::WordLookup(String::new()), "Serendipity.");
+        assert_eq!(
+            fallback,
+            Some(ReplyFallback::Run(MessageCommands::WordLookup(
+                "serendipity".into()
+            )))
+        );
+        let fallback = fallback_for(&MessageCommands::Etym(String::new()), "what a serendipity");
+        let Some(ReplyFallback::Pick(keyboard)) = fallback else {
+            panic!("expected to pick a word, got {:?}", fallback);
+        };
+        assert_eq!(keyboard.inline_keyboard[0][2].text, "serendipity");
+        let fallback = fallback_for(&MessageCommands::Say(String::new()), "what a serendipity");
+        let Some(ReplyFallback::Pick(keyboard)) = fallback else {
+            panic!("expected to pick a word, got {:?}", fallback);
+        };
+        assert_eq!(keyboard.inline_keyboard[0][0].text, "what");
+    }
+
+    #[test]
+    fn commands_with_argument_ignore_reply() {
+        let cmd = MessageCommands::WordLookup("run".into());
+        assert_eq!(fallback_for(&cmd, "serendipity"), None);
+        assert_eq!(fallback_for(&MessageCommands::Help, "serendipity"), None);
+    }
+}
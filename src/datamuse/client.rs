@@ -1,12 +1,41 @@
 use crate::datamuse::responses::Word;
 use crate::networking::api_client::ApiClient;
+use crate::networking::retry::RetryPolicy;
+use crate::networking::timeouts::Timeouts;
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct DatamuseClient {
     client: reqwest::Client,
+    retry: RetryPolicy,
 }
 
 impl DatamuseClient {
+    /// Creates a client for the Datamuse API sending its requests with the given reqwest client.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let datamuse = DatamuseClient::new(reqwest::Client::new());
+    /// ```
+    pub fn new(client: reqwest::Client) -> Self {
+        DatamuseClient {
+            client,
+            retry: RetryPolicy::default(),
+        }
+    }
+
+    /// Sets how the client's failed requests are retried, `RetryPolicy::default()` unless set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let datamuse = DatamuseClient::default().with_retry(RetryPolicy::default());
+    /// ```
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
     /// Creates an ApiClient configured for the Datamuse API.
     ///
     /// This constructs an `ApiClient` that uses this instance's HTTP client and is targeted
@@ -21,12 +50,14 @@ impl DatamuseClient {
     fn client(&self) -> ApiClient {
         ApiClient {
             client: rustify::Client::new("https://api.datamuse.com", self.client.clone()),
+            retry: self.retry.clone(),
         }
     }
-    /// Execute a Datamuse API endpoint and return the words from its response sorted in ascending order.
+    /// Execute a Datamuse API endpoint and return the words from its response as they are.
     ///
-    /// The provided `request` must implement `rustify::Endpoint` with `Response = Vec<Word>`. The result is the list
-    /// of `word` fields from the response, sorted lexicographically.
+    /// The provided `request` must implement `rustify::Endpoint` with `Response = Vec<Word>`. The words keep
+    /// Datamuse's order along with any metadata (score, tags) the request asked for, so that the caller
+    /// decides how to rank them.
     ///
     /// # Parameters
     ///
@@ -34,27 +65,85 @@ impl DatamuseClient {
     ///
     /// # Returns
     ///
-    /// A `Vec<String>` containing the `word` values from the endpoint response, sorted ascending by the word.
+    /// A `Vec<Word>` from the endpoint response.
     ///
     /// # Examples
     ///
     /// ```no_run
     /// # use crate::datamuse::client::DatamuseClient;
+    /// # use crate::datamuse::request::FindWordByMaskRequest;
     /// # async fn example() -> anyhow::Result<()> {
     /// let client = DatamuseClient::default();
-    /// // `request` should be any type implementing `rustify::Endpoint<Response = Vec<crate::datamuse::Word>>`
-    /// let request = /* build request */ ;
-    /// let words = client.exec(request).await?; // network access required
-    /// assert!(words.len() >= 0);
+    /// let words = client.exec(FindWordByMaskRequest::new("___ly".to_string())).await?; // network access required
+    /// let frequencies = words.iter().map(|word| word.frequency()).collect::<Vec<_>>();
     /// # Ok(())
     /// # }
     /// ```
     pub async fn exec<Endpoint: rustify::Endpoint<Response = Vec<Word>>>(
         &self,
         request: Endpoint,
+    ) -> anyhow::Result<Vec<Word>> {
+        self.client().exec(request).await
+    }
+
+    /// Execute a Datamuse API endpoint and return the words from its response ranked by relevance.
+    ///
+    /// The order is driven by the `score` Datamuse assigns to every
+    /// match, highest first; words without a score are placed last, keeping their original order.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<String>` containing the `word` values from the endpoint response, best match first.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use crate::datamuse::client::DatamuseClient;
+    /// # use crate::datamuse::request::FindWordByMeaningRequest;
+    /// # async fn example() -> anyhow::Result<()> {
+    /// let client = DatamuseClient::default();
+    /// let request = FindWordByMeaningRequest::new("a person who hoards books".to_string());
+    /// let words = client.exec_ranked(request).await?; // network access required
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn exec_ranked<Endpoint: rustify::Endpoint<Response = Vec<Word>>>(
+        &self,
+        request: Endpoint,
     ) -> anyhow::Result<Vec<String>> {
-        let mut words: Vec<Word> = self.client().exec(request).await?;
-        words.sort_by(|a, b| a.word.cmp(&b.word));
+        let mut words = self.exec(request).await?;
+        words.sort_by_key(|word| std::cmp::Reverse(word.score.unwrap_or_default()));
         Ok(words.into_iter().map(|word| word.word).collect())
     }
-}
\ No newline at end of file
+}
+
+impl Default for DatamuseClient {
+    /// Create a DatamuseClient with the default `Timeouts`.
+    fn default() -> Self {
+        DatamuseClient::new(Timeouts::default().client())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::datamuse::responses::Word;
+
+    #[test]
+    fn parsing_ranked_words_works() {
+        let response = "[{\"word\":\"bibliophile\",\"score\":40012086,\"tags\":[\"syn\",\"n\"]},{\"word\":\"bookworm\",\"score\":40004305,\"tags\":[\"n\"]},{\"word\":\"hoarder\"}]";
+        let words = serde_json::from_slice::<Vec<Word>>(response.as_bytes())
+            .unwrap_or_else(|err| panic!("Failed to parse results from datamuse: {}", err));
+        assert_eq!(words.len(), 3, "every item should be properly parsed");
+        assert_eq!(words[0].score, Some(40012086));
+        assert_eq!(words[2].score, None, "score is optional");
+    }
+
+    #[test]
+    fn parsing_frequencies_works() {
+        let response = "[{\"word\":\"only\",\"score\":1580,\"tags\":[\"f:1225.531284\"]},{\"word\":\"ugly\",\"score\":1405,\"tags\":[\"f:27.409611\"]},{\"word\":\"agley\",\"score\":5}]";
+        let words = serde_json::from_slice::<Vec<Word>>(response.as_bytes())
+            .unwrap_or_else(|err| panic!("Failed to parse results from datamuse: {}", err));
+        let frequencies = words.iter().map(Word::frequency).collect::<Vec<_>>();
+        assert_eq!(frequencies, vec![Some(1225.531284), Some(27.409611), None]);
+    }
+}
@@ -1,3 +1,3 @@
 pub mod client;
 pub mod request;
-mod responses;
+pub mod responses;
@@ -6,12 +6,17 @@ use rustify_derive::Endpoint;
 pub struct FindWordByMaskRequest {
     #[endpoint(query)]
     sp: String, // mask
+    #[endpoint(query)]
+    md: String, // metadata flags
+    #[endpoint(query)]
+    max: Option<u16>, // results limit, 100 by default
 }
 
 impl FindWordByMaskRequest {
     /// Creates a `FindWordByMaskRequest` from a mask, converting underscore wildcards to `?` to match Datamuse's mask syntax.
     ///
     /// The provided `mask` may use `_` to indicate a single-character wildcard; this constructor replaces all `_` with `?` and stores the result in the request's `sp` query field.
+    /// Word frequencies are always requested (`md=f`), so that the results can be ranked by how common they are.
     ///
     /// # Examples
     ///
@@ -22,6 +27,130 @@ impl FindWordByMaskRequest {
     pub fn new(mask: String) -> Self {
         Self {
             sp: mask.replace("_", "?"),
+            md: "f".to_string(),
+            max: None,
+        }
+    }
+
+    /// Overrides how many words Datamuse may return at most, up to 1000.
+    ///
+    /// Useful when the results are going to be filtered locally, so that the filter has more to choose from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let req = FindWordByMaskRequest::new("____".to_string()).max(1000);
+    /// assert_eq!(req.max, Some(1000));
+    /// ```
+    pub fn max(mut self, max: u16) -> Self {
+        self.max = Some(max);
+        self
+    }
+}
+#[derive(Endpoint)]
+#[endpoint(path = "/words", response = "Vec<Word>")]
+pub struct FindWordByMeaningRequest {
+    #[endpoint(query)]
+    ml: String, // description
+}
+
+impl FindWordByMeaningRequest {
+    /// Creates a `FindWordByMeaningRequest` asking Datamuse for words whose meaning matches the description.
+    ///
+    /// The description is passed verbatim as the `ml` ("means like") query parameter, so it can be
+    /// a single word or a whole phrase, e.g. `"a person who hoards books"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let req = FindWordByMeaningRequest::new("a person who hoards books".to_string());
+    /// assert_eq!(req.ml, "a person who hoards books");
+    /// ```
+    pub fn new(description: String) -> Self {
+        Self { ml: description }
+    }
+}
+
+#[derive(Endpoint)]
+#[endpoint(path = "/words", response = "Vec<Word>")]
+pub struct FindWordByClueRequest {
+    #[endpoint(query)]
+    sp: String, // mask
+    #[endpoint(query)]
+    ml: String, // clue
+}
+
+impl FindWordByClueRequest {
+    /// Creates a `FindWordByClueRequest` looking for words that both fit the mask and match the clue's meaning.
+    ///
+    /// The mask follows the same conventions as in [`FindWordByMaskRequest::new`], i.e. `_` is converted to `?`,
+    /// while the clue is passed verbatim as the `ml` ("means like") query parameter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let req = FindWordByClueRequest::new("c_t".to_string(), "feline pet".to_string());
+    /// assert_eq!(req.sp, "c?t");
+    /// assert_eq!(req.ml, "feline pet");
+    /// ```
+    pub fn new(mask: String, clue: String) -> Self {
+        Self {
+            sp: mask.replace("_", "?"),
+            ml: clue,
         }
     }
-}
\ No newline at end of file
+}
+
+#[derive(Endpoint)]
+#[endpoint(path = "/sug", response = "Vec<Word>")]
+pub struct SuggestWordsRequest {
+    #[endpoint(query)]
+    s: String, // possibly misspelled word
+}
+
+impl SuggestWordsRequest {
+    /// Creates a `SuggestWordsRequest` asking Datamuse for correctly spelled words close to the given one.
+    ///
+    /// The word is passed verbatim as the `s` query parameter of the `/sug` endpoint, which is
+    /// tolerant to typos, e.g. `"recieve"` yields `"receive"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let req = SuggestWordsRequest::new("recieve".to_string());
+    /// assert_eq!(req.s, "recieve");
+    /// ```
+    pub fn new(word: String) -> Self {
+        Self { s: word }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustify::Endpoint;
+
+    #[test]
+    fn mask_request_skips_default_max() {
+        let request = FindWordByMaskRequest::new("c_t".to_string());
+        let query = request.query().unwrap_or_default();
+        assert_eq!(query, Some("sp=c%3Ft&md=f".to_string()));
+    }
+
+    #[test]
+    fn mask_request_passes_max() {
+        let request = FindWordByMaskRequest::new("____".to_string()).max(1000);
+        let query = request.query().unwrap_or_default();
+        assert_eq!(query, Some("sp=%3F%3F%3F%3F&md=f&max=1000".to_string()));
+    }
+
+    #[test]
+    fn suggest_request_targets_sug_endpoint() {
+        let request = SuggestWordsRequest::new("recieve".to_string());
+        assert_eq!(request.path(), "/sug");
+        assert_eq!(
+            request.query().unwrap_or_default(),
+            Some("s=recieve".to_string())
+        );
+    }
+}
@@ -3,4 +3,32 @@ use serde::Deserialize;
 #[derive(Debug, Clone, Deserialize)]
 pub struct Word {
     pub word: String,
+    #[serde(default)]
+    pub score: Option<u64>,
+    #[serde(default)]
+    pub tags: Option<Vec<String>>,
+}
+
+impl Word {
+    /// Returns how common the word is, in occurrences per million words of English text.
+    ///
+    /// Datamuse reports it as an `f:<number>` tag when the request asks for `md=f` metadata.
+    ///
+    /// # Returns
+    ///
+    /// `Some(frequency)` if the word carries a well-formed frequency tag, `None` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let word = Word { word: "only".into(), score: None, tags: Some(vec!["f:1225.5".into()]) };
+    /// assert_eq!(word.frequency(), Some(1225.5));
+    /// ```
+    pub fn frequency(&self) -> Option<f64> {
+        self.tags
+            .iter()
+            .flatten()
+            .find_map(|tag| tag.strip_prefix("f:"))
+            .and_then(|freq| freq.parse().ok())
+    }
 }
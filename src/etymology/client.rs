@@ -0,0 +1,163 @@
+use crate::etymology::ExtractsResponse;
+use crate::networking::api_client::ApiClient;
+use crate::networking::retry::RetryPolicy;
+use crate::networking::timeouts::Timeouts;
+
+/// The English Wiktionary, used unless configured otherwise.
+pub const DEFAULT_BASE_URL: &str = "https://en.wiktionary.org/w";
+
+#[derive(Debug, Clone)]
+pub struct EtymologyClient {
+    client: reqwest::Client,
+    base_url: String,
+    retry: RetryPolicy,
+}
+
+impl EtymologyClient {
+    /// Create a client for a MediaWiki API serving Wiktionary pages.
+    ///
+    /// The `base_url` is the root the `/api.php` path is resolved against, which allows
+    /// pointing the client at a mirror or at a local fixture server.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let client = EtymologyClient::new(reqwest::Client::new(), "http://127.0.0.1:8082".to_string());
+    /// ```
+    pub fn new(client: reqwest::Client, base_url: String) -> Self {
+        EtymologyClient {
+            client,
+            base_url,
+            retry: RetryPolicy::default(),
+        }
+    }
+
+    /// Sets how the client's failed requests are retried, `RetryPolicy::default()` unless set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let client = EtymologyClient::default().with_retry(RetryPolicy {
+    ///     max_attempts: 5,
+    ///     ..Default::default()
+    /// });
+    /// ```
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Creates an ApiClient targeted at the configured base URL, using the internal reqwest client.
+    fn client(&self) -> ApiClient {
+        ApiClient {
+            client: rustify::Client::new(&self.base_url, self.client.clone()),
+            retry: self.retry.clone(),
+        }
+    }
+
+    /// Execute the given Endpoint against the MediaWiki API and extract the word's etymologies.
+    ///
+    /// # Returns
+    ///
+    /// The etymologies from the English section of the word's page, empty if there are none or the page is missing.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() -> anyhow::Result<()> {
+    /// let client = EtymologyClient::default();
+    /// let etymologies = client.exec(EtymologyRequest::new("run".to_string())).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn exec<Endpoint: rustify::Endpoint<Response = ExtractsResponse>>(
+        &self,
+        request: Endpoint,
+    ) -> anyhow::Result<Vec<String>> {
+        self.client().exec(request).await
+    }
+}
+
+impl Default for EtymologyClient {
+    /// Create an EtymologyClient targeting the English Wiktionary with the default `Timeouts`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let _: EtymologyClient = EtymologyClient::default();
+    /// ```
+    fn default() -> Self {
+        EtymologyClient::new(Timeouts::default().client(), DEFAULT_BASE_URL.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::etymology::requests::EtymologyRequest;
+    use crate::networking::fixtures::serve;
+    use axum::extract::Query;
+    use axum::http::header::USER_AGENT;
+    use axum::http::{HeaderMap, StatusCode};
+    use axum::routing::get;
+    use std::collections::HashMap;
+
+    /// Serves a fixture MediaWiki API on a random local port, returning the base URL to reach it.
+    async fn fixture_server() -> String {
+        let app = axum::Router::new().route(
+            "/api.php",
+            get(|Query(params): Query<HashMap<String, String>>| async move {
+                match params.get("titles").map(String::as_str) {
+                    Some("run") => "{\"query\":{\"pages\":[{\"title\":\"run\",\"extract\":\"== English ==\\n=== Etymology ===\\nFrom Middle English rennen.\\n=== Verb ===\\nrun\"}]}}",
+                    _ => "{\"query\":{\"pages\":[{\"title\":\"qwrtz\",\"missing\":true}]}}",
+                }
+            }),
+        );
+        serve(app).await
+    }
+
+    #[tokio::test]
+    async fn known_word_has_etymology() {
+        let client = EtymologyClient::new(Default::default(), fixture_server().await);
+        let etymologies = client
+            .exec(EtymologyRequest::new("run".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(etymologies, vec!["From Middle English rennen."]);
+    }
+
+    #[tokio::test]
+    async fn requests_identify_the_bot() {
+        // like Wikimedia, the fixture turns away requests without a User-Agent
+        let app = axum::Router::new().route(
+            "/api.php",
+            get(|headers: HeaderMap| async move {
+                match headers
+                    .get(USER_AGENT)
+                    .and_then(|agent| agent.to_str().ok())
+                {
+                    Some(agent) if agent.starts_with("words-lookup-bot/") => {
+                        (StatusCode::OK, "{\"query\":{\"pages\":[]}}")
+                    }
+                    _ => (StatusCode::FORBIDDEN, "{}"),
+                }
+            }),
+        );
+        let client = EtymologyClient::new(Timeouts::default().client(), serve(app).await);
+        let etymologies = client
+            .exec(EtymologyRequest::new("run".to_string()))
+            .await
+            .unwrap();
+        assert!(etymologies.is_empty());
+    }
+
+    #[tokio::test]
+    async fn unknown_word_has_no_etymology() {
+        let client = EtymologyClient::new(Default::default(), fixture_server().await);
+        let etymologies = client
+            .exec(EtymologyRequest::new("qwrtz".to_string()))
+            .await
+            .unwrap();
+        assert!(etymologies.is_empty());
+    }
+}
@@ -0,0 +1,61 @@
+use crate::etymology::ExtractsResponse;
+use rustify_derive::Endpoint;
+
+#[derive(Endpoint)]
+#[endpoint(path = "/api.php", response = "ExtractsResponse")]
+pub struct EtymologyRequest {
+    #[endpoint(query)]
+    action: String,
+    #[endpoint(query)]
+    prop: String, // page extracts
+    #[endpoint(query)]
+    explaintext: u8, // plain text instead of HTML
+    #[endpoint(query)]
+    redirects: u8, // follow redirects, e.g. from other spellings
+    #[endpoint(query)]
+    format: String,
+    #[endpoint(query)]
+    formatversion: u8, // pages as a list instead of a map by id
+    #[endpoint(query)]
+    titles: String, // the word
+}
+
+impl EtymologyRequest {
+    /// Creates an `EtymologyRequest` asking MediaWiki for the plain text of the word's Wiktionary page.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let req = EtymologyRequest::new("run".to_string());
+    /// assert_eq!(req.titles, "run");
+    /// ```
+    pub fn new(word: String) -> Self {
+        Self {
+            action: "query".to_string(),
+            prop: "extracts".to_string(),
+            explaintext: 1,
+            redirects: 1,
+            format: "json".to_string(),
+            formatversion: 2,
+            titles: word,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustify::Endpoint;
+
+    #[test]
+    fn etymology_request_queries_plain_extracts() {
+        let request = EtymologyRequest::new("run".to_string());
+        assert_eq!(
+            request.query().unwrap_or_default(),
+            Some(
+                "action=query&prop=extracts&explaintext=1&redirects=1&format=json&formatversion=2&titles=run"
+                    .to_string()
+            )
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use serde::Deserialize;
+
+/// How many etymologies of a word are kept, e.g. "bank" has a separate one for each of its unrelated senses.
+const MAX_ETYMOLOGIES: usize = 3;
+/// How many characters of an etymology are kept, to fit a message.
+const MAX_ETYMOLOGY_LEN: usize = 800;
+
+#[derive(Deserialize, Debug)]
+pub struct ExtractsResponse {
+    #[serde(default)]
+    pub query: Option<QueryResponse>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct QueryResponse {
+    #[serde(default)]
+    pub pages: Vec<PageResponse>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PageResponse {
+    #[serde(default)]
+    pub extract: Option<String>,
+}
+
+impl From<ExtractsResponse> for Vec<String> {
+    /// Extracts the etymologies from the plain text of the Wiktionary pages.
+    ///
+    /// Missing pages carry no extract and yield no etymologies.
+    fn from(response: ExtractsResponse) -> Self {
+        response
+            .query
+            .map(|query| query.pages)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|page| page.extract.as_deref())
+            .flat_map(etymologies)
+            .take(MAX_ETYMOLOGIES)
+            .collect()
+    }
+}
+
+/// Splits a heading line like `=== Etymology 1 ===` into its level and title.
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|c| *c == '=').count();
+    if level < 2 {
+        return None;
+    }
+    let title = line[level..].strip_suffix(&"=".repeat(level))?;
+    Some((level, title.trim()))
+}
+
+/// Collects the text under the "Etymology" headings of the English section of a Wiktionary page.
+///
+/// Each etymology is a separate entry, its paragraphs are joined with new lines,
+/// and it is cut to `MAX_ETYMOLOGY_LEN` characters.
+///
+/// # Examples
+///
+/// ```ignore
+/// let extract = "== English ==\n=== Etymology ===\nFrom Middle English rennen.\n=== Verb ===\nrun";
+/// assert_eq!(etymologies(extract), vec!["From Middle English rennen."]);
+/// ```
+pub fn etymologies(extract: &str) -> Vec<String> {
+    let mut in_english = false;
+    let mut current: Option<String> = None;
+    let mut found = vec![];
+    for line in extract.lines().map(str::trim) {
+        match heading(line) {
+            Some((level, title)) => {
+                found.extend(current.take().filter(|text| !text.is_empty()));
+                if level == 2 {
+                    in_english = title == "English";
+                } else if in_english && title.starts_with("Etymology") {
+                    current = Some(String::new());
+                }
+            }
+            None => {
+                if let Some(text) = current.as_mut()
+                    && !line.is_empty()
+                {
+                    if !text.is_empty() {
+                        text.push('\n');
+                    }
+                    text.push_str(line);
+                }
+            }
+        }
+    }
+    found.extend(current.filter(|text| !text.is_empty()));
+    found.into_iter().map(shortened).collect()
+}
+
+/// Cuts the text to `MAX_ETYMOLOGY_LEN` characters, marking the cut with an ellipsis.
+fn shortened(text: String) -> String {
+    match text.char_indices().nth(MAX_ETYMOLOGY_LEN) {
+        Some((end, _)) => format!("{}…", &text[..end]),
+        None => text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXTRACT: &str = "\n\n\n== English ==\n\n\n=== Etymology 1 ===\nFrom Middle English bank, from Old Norse bakki.\n\n\n==== Noun ====\nbank (plural banks)\n\n\n=== Etymology 2 ===\nFrom Middle French banque.\n\nDoublet of bench.\n\n\n==== Noun ====\nbank\n\n\n== Dutch ==\n\n\n=== Etymology ===\nFrom Middle Dutch banc.";
+
+    #[test]
+    fn parsing_etymologies_works() {
+        let response = format!(
+            "{{\"batchcomplete\":true,\"query\":{{\"pages\":[{{\"pageid\":1,\"ns\":0,\"title\":\"bank\",\"extract\":{:?}}}]}}}}",
+            EXTRACT
+        );
+        let parsed = serde_json::from_str::<ExtractsResponse>(&response).unwrap();
+        let etymologies: Vec<String> = parsed.into();
+        assert_eq!(
+            etymologies,
+            vec![
+                "From Middle English bank, from Old Norse bakki.",
+                "From Middle French banque.\nDoublet of bench.",
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_page_has_no_etymologies() {
+        let response = "{\"batchcomplete\":true,\"query\":{\"pages\":[{\"ns\":0,\"title\":\"qwrtz\",\"missing\":true}]}}";
+        let parsed = serde_json::from_str::<ExtractsResponse>(response).unwrap();
+        let etymologies: Vec<String> = parsed.into();
+        assert!(etymologies.is_empty());
+    }
+
+    #[test]
+    fn long_etymologies_are_shortened() {
+        let extract = format!("== English ==\n=== Etymology ===\n{}", "a".repeat(1000));
+        let etymologies = etymologies(&extract);
+        assert_eq!(etymologies[0].chars().count(), MAX_ETYMOLOGY_LEN + 1);
+        assert!(etymologies[0].ends_with('…'));
+    }
+}
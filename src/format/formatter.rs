@@ -1,5 +1,8 @@
 use crate::format::LinksProvider;
-use crate::stands4::{AbbreviationDefinition, PhraseDefinition, SynAntDefinitions, WordDefinition};
+use crate::settings::ChatSettings;
+use crate::stands4::{
+    AbbreviationDefinition, PhraseDefinition, QuoteDefinition, SynAntDefinitions, WordDefinition,
+};
 use crate::urban::UrbanDefinition;
 use regex::Regex;
 use std::fmt::Debug;
@@ -11,12 +14,32 @@ pub trait LookupFormatter {
     type Value: Send + Sync;
     fn on_empty() -> Self::Value;
     fn link_provider(&self) -> &LinksProvider;
+    fn settings(&self) -> &ChatSettings;
+    fn with_settings(self, settings: ChatSettings) -> Self;
+    /// How many entries of a lookup are visited, the chat's `max_results` unless the formatter
+    /// shows the entries in pages.
+    fn max_results(&self) -> usize {
+        self.settings().max_results
+    }
     fn visit_word(&mut self, i: usize, def: &WordDefinition);
     fn visit_phrase(&mut self, i: usize, def: &PhraseDefinition);
     fn visit_abbreviations(&mut self, i: usize, category: &str, defs: &[&AbbreviationDefinition]);
+    fn visit_abbreviation_category(
+        &mut self,
+        i: usize,
+        category: &str,
+        defs: &[&AbbreviationDefinition],
+    );
     fn visit_syn_ant(&mut self, i: usize, def: &SynAntDefinitions);
     fn visit_urban_definition(&mut self, i: usize, def: &UrbanDefinition);
     fn visit_word_finder_definition(&mut self, i: usize, def: String);
+    fn visit_reverse_match(&mut self, i: usize, word: &str, def: Option<&WordDefinition>);
+    fn visit_spelling_suggestions(&mut self, word: &str, suggestions: &[String]);
+    fn visit_lemma(&mut self, lemma: &str, word: &str);
+    fn visit_pronunciation(&mut self, word: &str, ipa: &str);
+    fn visit_etymology(&mut self, i: usize, word: &str, etymology: &str);
+    fn visit_quote(&mut self, i: usize, def: &QuoteDefinition);
+    fn visit_section(&mut self, source: &str, available: bool);
     fn append_title(&mut self, title: String);
     fn append_link(&mut self, link: String);
     fn build(self) -> Result<Self::Value, Self::Error>;
@@ -86,10 +109,50 @@ pub fn as_in(example: &str) -> String {
     compose_multiline("*As in*: ", example)
 }
 
+/// Telegram refuses messages longer than this many characters.
+pub const MAX_MESSAGE_LEN: usize = 4096;
+
+/// Marks the end of a message that was cut, escaped for MarkdownV2.
+const CUT_MARK: &str = "\\.\\.\\.";
+
+/// Cuts a MarkdownV2 message down to Telegram's length limit, dropping whole entries from its end.
+///
+/// The entries are the blocks of the message separated by blank lines, or its lines if even the first
+/// entry is too long, so that no markup is left unclosed. A cut message ends with an ellipsis.
+/// The length is counted in UTF-16 code units, as Telegram does.
+///
+/// # Examples
+///
+/// ```
+/// let text = format!("{}\n\n{}", "a".repeat(10), "b".repeat(MAX_MESSAGE_LEN));
+/// assert_eq!(truncate_message(text), format!("{}\n\n\\.\\.\\.", "a".repeat(10)));
+/// ```
+pub fn truncate_message(text: String) -> String {
+    if text.encode_utf16().count() <= MAX_MESSAGE_LEN {
+        return text;
+    }
+    let limit = MAX_MESSAGE_LEN - CUT_MARK.len();
+    let mut units = 0;
+    let end = text
+        .char_indices()
+        .find(|(_, c)| {
+            units += c.len_utf16();
+            units > limit
+        })
+        .map_or(text.len(), |(i, _)| i);
+    let fitting = &text[..end];
+    let cut = fitting
+        .rfind("\n\n")
+        .map(|i| i + 2)
+        .or_else(|| fitting.rfind('\n').map(|i| i + 1))
+        .unwrap_or(0);
+    format!("{}{}", &text[..cut], CUT_MARK)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::format::formatter::lines_of;
-    use crate::format::meaning;
+    use crate::format::{MAX_MESSAGE_LEN, meaning, truncate_message};
 
     #[test]
     fn parsing_multiline() {
@@ -138,4 +201,22 @@ mod tests {
         let expected = format!("*Meaning*: {}", text);
         assert_eq!(expected, meaning);
     }
+
+    #[test]
+    fn truncating_keeps_whole_entries() {
+        // GIVEN
+        let entry = format!("*Meaning*: {}\n", "a".repeat(1000));
+        let text = vec![entry.clone(); 5].join("\n");
+        // WHEN
+        let truncated = truncate_message(text);
+        // THEN
+        assert!(truncated.encode_utf16().count() <= MAX_MESSAGE_LEN);
+        assert_eq!(truncated, format!("{}\\.\\.\\.", vec![entry; 4].join("\n") + "\n"));
+    }
+
+    #[test]
+    fn short_message_is_kept() {
+        let text = "*Meaning*: short".to_string();
+        assert_eq!(truncate_message(text.clone()), text);
+    }
 }
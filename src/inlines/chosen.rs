@@ -0,0 +1,199 @@
+use crate::bloc::common::CommandHandler;
+use crate::inlines::formatting::SEND_ALL_ID;
+use crate::inlines::{QueryCommands, extract_command};
+use serde::Serialize;
+use std::fs::OpenOptions;
+use std::path::Path;
+use std::sync::Arc;
+use teloxide::dispatching::UpdateFilterExt;
+use teloxide::types::{ChosenInlineResult, Update};
+use tokio::fs::File;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+
+/// An inline result a user has sent to a chat, as recorded for the analytics.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ChosenResult {
+    /// When the result was chosen, in RFC 3339.
+    pub at: String,
+    /// What the query asked for, e.g. `word` or `urban`.
+    pub kind: &'static str,
+    /// Where the results of the query come from, e.g. `stands4`,
+    /// or the ID of the picked suggestion, e.g. `wordle`.
+    pub source: String,
+    /// The position of the result among the offered ones, starting from 0,
+    /// or `None` for the article sending all the definitions at once and for suggestions.
+    pub rank: Option<usize>,
+    /// The looked up term, as typed by the user after the prefix.
+    pub term: String,
+}
+
+/// Describes what the inline query asked for and where its results come from.
+///
+/// # Returns
+///
+/// The kind of the query, the source of its results and the looked up term.
+///
+/// # Examples
+///
+/// ```
+/// let command = QueryCommands::UrbanLookup("yeet".to_string());
+/// assert_eq!(describe_query(&command), ("urban", "urban_dictionary", "yeet"));
+/// ```
+fn describe_query(command: &QueryCommands) -> (&'static str, &'static str, &str) {
+    match command {
+        QueryCommands::Suggestions => ("suggestions", "stands4", ""),
+        QueryCommands::WordLookup(term) => ("word", "stands4", term),
+        QueryCommands::PhraseLookup(term) => ("phrase", "stands4", term),
+        QueryCommands::UrbanLookup(term) => ("urban", "urban_dictionary", term),
+        QueryCommands::ThesaurusLookup(term) => ("thesaurus", "stands4", term),
+        QueryCommands::QuoteLookup(term) => ("quote", "stands4", term),
+        QueryCommands::AbbrLookup(term) => ("abbr", "stands4", term),
+        QueryCommands::AllLookup(term) => ("all", "all", term),
+        QueryCommands::Finder(term) => ("finder", "datamuse", term),
+        QueryCommands::Clue(term) => ("clue", "datamuse", term),
+    }
+}
+
+/// Turns the report of a chosen inline result into a record of the analytics.
+///
+/// The query is parsed the same way it was when the results were offered, and the rank is taken
+/// from the `answer-<i>` ID the result was given, unless it's the article sending all the definitions.
+/// A picked suggestion is recorded by its ID, e.g. `help` or `wordle`, in place of the source.
+///
+/// # Returns
+///
+/// `Some(ChosenResult)`, or `None` if the query or the result ID isn't recognized.
+fn chosen_result(result: &ChosenInlineResult) -> Option<ChosenResult> {
+    let command = extract_command(result.query.clone())?;
+    let (kind, source, term) = describe_query(&command);
+    let (source, rank) = match (&command, result.result_id.as_str()) {
+        (QueryCommands::Suggestions, id) => (id.to_string(), None),
+        (_, SEND_ALL_ID) => (source.to_string(), None),
+        (_, id) => (
+            source.to_string(),
+            Some(id.strip_prefix("answer-")?.parse().ok()?),
+        ),
+    };
+    Some(ChosenResult {
+        at: chrono::Utc::now().to_rfc3339(),
+        kind,
+        source,
+        rank,
+        term: term.to_string(),
+    })
+}
+
+/// Where the chosen inline results are recorded, as JSON lines appended to a file.
+///
+/// The file is kept open for as long as the bot runs.
+#[derive(Debug, Clone)]
+pub struct ChosenResultsLog {
+    file: Arc<Mutex<File>>,
+}
+
+impl ChosenResultsLog {
+    /// Opens the log appending to the file at `path`, which is created if missing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the file can't be opened for appending.
+    pub fn open(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(ChosenResultsLog {
+            file: Arc::new(Mutex::new(File::from_std(file))),
+        })
+    }
+
+    /// Appends the record to the file as a single line of JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the file can't be written to.
+    pub async fn record(&self, chosen: &ChosenResult) -> anyhow::Result<()> {
+        let mut line = serde_json::to_string(chosen)?;
+        line.push('\n');
+        // the lock keeps the lines of concurrent records from interleaving
+        let mut file = self.file.lock().await;
+        file.write_all(line.as_bytes()).await?;
+        file.flush().await?;
+        Ok(())
+    }
+}
+
+/// Builds the handler recording the inline results users have sent to chats.
+///
+/// Telegram only reports the chosen results of bots with inline feedback enabled in @BotFather.
+/// A failure to record is logged and doesn't affect the users.
+///
+/// # Examples
+///
+/// ```
+/// let handler = chosen_results_tree();
+/// // Mount `handler` into a teloxide dispatcher dptree.
+/// ```
+pub fn chosen_results_tree() -> CommandHandler {
+    Update::filter_chosen_inline_result()
+        .filter_map(|result: ChosenInlineResult| chosen_result(&result))
+        .endpoint(|log: ChosenResultsLog, chosen: ChosenResult| async move {
+            if let Err(err) = log.record(&chosen).await {
+                log::error!("Failed to record chosen inline result: {:?}", err);
+            }
+            Ok(())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_is_described_by_its_prefix() {
+        let command = extract_command("u.no cap".to_string()).unwrap();
+        assert_eq!(
+            describe_query(&command),
+            ("urban", "urban_dictionary", "no cap")
+        );
+        let command = extract_command("run".to_string()).unwrap();
+        assert_eq!(describe_query(&command), ("word", "stands4", "run"));
+    }
+
+    #[test]
+    fn suggestions_are_recorded_by_id() {
+        let command = extract_command(String::new()).unwrap();
+        assert_eq!(command, QueryCommands::Suggestions);
+        let result = serde_json::from_value::<ChosenInlineResult>(serde_json::json!({
+            "result_id": "wordle",
+            "from": { "id": 1, "is_bot": false, "first_name": "Jane" },
+            "query": "",
+        }))
+        .unwrap();
+        let chosen = chosen_result(&result).unwrap();
+        assert_eq!(chosen.kind, "suggestions");
+        assert_eq!(chosen.source, "wordle");
+        assert_eq!(chosen.rank, None);
+    }
+
+    #[tokio::test]
+    async fn records_are_appended_as_lines() {
+        let path = std::env::temp_dir().join(format!("chosen-{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let log = ChosenResultsLog::open(&path).unwrap();
+        let chosen = ChosenResult {
+            at: "2025-01-01T00:00:00+00:00".to_string(),
+            kind: "word",
+            source: "stands4".to_string(),
+            rank: Some(2),
+            term: "run".to_string(),
+        };
+        log.record(&chosen).await.unwrap();
+        log.record(&chosen).await.unwrap();
+
+        let lines = std::fs::read_to_string(&path).unwrap();
+        let lines = lines.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].contains(r#""rank":2"#));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
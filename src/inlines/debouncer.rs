@@ -1,77 +1,206 @@
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
+use teloxide::Bot;
 use teloxide::payloads::AnswerInlineQuerySetters;
 use teloxide::prelude::{Requester, UserId};
 use teloxide::types::{InlineQuery, InlineQueryId};
-use teloxide::Bot;
+use tokio::sync::oneshot;
 use tokio::time::Instant;
 
+/// How long a query waits for the next one by default, before it's looked up.
+const DEFAULT_DEBOUNCE_WINDOW: Duration = Duration::from_secs(1);
+/// How often the users who have stopped typing are forgotten.
+const SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+/// The latest query of a user.
+#[derive(Debug)]
+struct Latest {
+    at: Instant,
+    cancel: oneshot::Sender<()>,
+}
+
+#[derive(Debug)]
+struct Users {
+    latest: HashMap<UserId, Latest>,
+    swept_at: Instant,
+}
+
+/// Holds an inline query back until the user stops typing, so that only the last of the queries
+/// sent while typing is looked up.
+///
+/// A query sent within the window after the previous one of the same user waits for the window
+/// to pass, and is aborted as soon as a newer query of the user arrives. A query sent after a pause
+/// is looked up right away. The users who have stopped typing are forgotten every `SWEEP_INTERVAL`.
 #[derive(Debug, Clone)]
 pub struct InlineQueryDebouncer {
-    queries_per_user: Arc<Mutex<HashMap<UserId, (InlineQueryId, Instant)>>>,
-    duration: Duration,
+    users: Arc<Mutex<Users>>,
+    window: Duration,
+}
+
+/// A registered query waiting to be looked up.
+#[derive(Debug)]
+pub struct PendingQuery {
+    delay: Duration,
+    cancelled: oneshot::Receiver<()>,
 }
 
 impl InlineQueryDebouncer {
-    pub fn new() -> Self {
+    /// Creates a debouncer holding a query back for `window` after the previous one.
+    pub fn new(window: Duration) -> Self {
         Self {
-            queries_per_user: Arc::default(),
-            duration: Duration::from_secs(1),
+            users: Arc::new(Mutex::new(Users {
+                latest: HashMap::new(),
+                swept_at: Instant::now(),
+            })),
+            window,
         }
     }
 
-    pub fn get(&self, user_id: UserId) -> Option<InlineQueryId> {
-        self.queries_per_user.lock().unwrap()
-            .get(&user_id)
-            .map(|(query_id, _)| query_id.clone())
+    /// Registers the user's query as their latest one, aborting the previous query if it's still waiting.
+    ///
+    /// # Returns
+    ///
+    /// The pending query to [`PendingQuery::wait`] for.
+    pub fn register(&self, user: UserId) -> PendingQuery {
+        let now = Instant::now();
+        let mut users = self.users.lock().unwrap();
+        if now.duration_since(users.swept_at) >= SWEEP_INTERVAL {
+            users
+                .latest
+                .retain(|_, latest| now.duration_since(latest.at) < self.window);
+            users.swept_at = now;
+        }
+
+        let (cancel, cancelled) = oneshot::channel();
+        let delay = match users.latest.insert(user, Latest { at: now, cancel }) {
+            Some(previous) => {
+                // fails if the previous query is no longer waiting, which is fine
+                let _ = previous.cancel.send(());
+                match now.duration_since(previous.at) < self.window {
+                    true => self.window,
+                    false => Duration::ZERO,
+                }
+            }
+            None => Duration::ZERO,
+        };
+        PendingQuery { delay, cancelled }
     }
 
-    pub fn replace(
-        &mut self,
-        user_id: UserId,
-        query_id: InlineQueryId,
-    ) -> Option<InlineQueryId> {
-        let query_time = Instant::now();
-        let mut existing = self.queries_per_user.lock().unwrap();
-        match existing.insert(user_id, (query_id.clone(), query_time)) {
-            Some((prev_query_id, prev_time))
-            if query_time.duration_since(prev_time).lt(&self.duration)
-            => Some(prev_query_id),
-            _ => None
-        }
+    /// How many users are remembered, i.e. have queried since the last sweep.
+    #[cfg(test)]
+    fn remembered(&self) -> usize {
+        self.users.lock().unwrap().latest.len()
     }
 }
+
 impl Default for InlineQueryDebouncer {
     fn default() -> Self {
-        Self::new()
+        Self::new(DEFAULT_DEBOUNCE_WINDOW)
     }
 }
 
-async fn cancel_old_query(
-    bot: Bot,
-    user_id: UserId,
-    query_id: InlineQueryId,
-    mut debouncer: InlineQueryDebouncer,
-) {
-    if let Some(prev_query_id) = debouncer.replace(user_id, query_id) {
-        let _ = bot.answer_inline_query(prev_query_id, vec![])
-            .cache_time(0)
-            .await;
+impl PendingQuery {
+    /// Waits until the query should be looked up.
+    ///
+    /// # Returns
+    ///
+    /// `true` once the query's delay has passed, or `false` as soon as a newer query of the user aborts it.
+    pub async fn wait(mut self) -> bool {
+        tokio::select! {
+            _ = tokio::time::sleep(self.delay) => true,
+            // a dropped sender means the user got forgotten, not that the query got aborted
+            Ok(()) = &mut self.cancelled => false,
+        }
     }
 }
 
+/// Lets the inline query through once the user has stopped typing.
+///
+/// An aborted query is answered with no results right away, so that the user's client doesn't wait for it.
+///
+/// # Returns
+///
+/// `true` if the query should be looked up, `false` if a newer query of the user superseded it.
 pub async fn debounce_inline_queries(
     bot: Bot,
     InlineQuery { from, id, .. }: InlineQuery,
     debouncer: InlineQueryDebouncer,
 ) -> bool {
-    tokio::join!(
-        cancel_old_query(bot, from.id, id.clone(), debouncer.clone()),
-        tokio::time::sleep(debouncer.duration)
+    let proceed = debouncer.register(from.id).wait().await;
+    log::debug!(
+        "[DEBOUNCING] {:?} // {:?} proceeds: {}",
+        from.id,
+        id,
+        proceed
     );
+    if !proceed {
+        let _ = answer_superseded(bot, id).await;
+    }
+    proceed
+}
 
-    let stored = debouncer.get(from.id);
-    log::info!("[DEBOUNCING] CHECK {:?} // {:?} = {:?}", from.id, id, stored);
-    stored == Some(id)
-}
\ No newline at end of file
+async fn answer_superseded(bot: Bot, query_id: InlineQueryId) -> anyhow::Result<()> {
+    let _ = bot
+        .answer_inline_query(query_id, vec![])
+        .cache_time(0)
+        .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USER: UserId = UserId(1);
+    const WINDOW: Duration = Duration::from_millis(500);
+
+    #[tokio::test(start_paused = true)]
+    async fn query_after_pause_proceeds_right_away() {
+        let debouncer = InlineQueryDebouncer::new(WINDOW);
+        let started = Instant::now();
+        assert!(debouncer.register(USER).wait().await);
+        assert_eq!(started.elapsed(), Duration::ZERO);
+
+        tokio::time::advance(WINDOW * 2).await;
+        let started = Instant::now();
+        assert!(debouncer.register(USER).wait().await);
+        assert_eq!(started.elapsed(), Duration::ZERO);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn superseded_query_is_aborted_without_waiting() {
+        let debouncer = InlineQueryDebouncer::new(WINDOW);
+        assert!(debouncer.register(USER).wait().await);
+
+        let started = Instant::now();
+        let superseded = tokio::spawn(debouncer.register(USER).wait());
+        tokio::time::advance(WINDOW / 5).await;
+        let latest = tokio::spawn(debouncer.register(USER).wait());
+
+        assert!(!superseded.await.unwrap());
+        assert_eq!(started.elapsed(), WINDOW / 5);
+        assert!(latest.await.unwrap());
+        assert_eq!(started.elapsed(), WINDOW / 5 + WINDOW);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn other_users_are_not_affected() {
+        let debouncer = InlineQueryDebouncer::new(WINDOW);
+        assert!(debouncer.register(USER).wait().await);
+        let waiting = tokio::spawn(debouncer.register(USER).wait());
+        assert!(debouncer.register(UserId(2)).wait().await);
+        assert!(waiting.await.unwrap());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn users_who_stopped_typing_are_forgotten() {
+        let debouncer = InlineQueryDebouncer::new(WINDOW);
+        assert!(debouncer.register(USER).wait().await);
+        assert_eq!(debouncer.remembered(), 1);
+
+        tokio::time::advance(SWEEP_INTERVAL).await;
+        assert!(debouncer.register(UserId(2)).wait().await);
+        assert_eq!(debouncer.remembered(), 1);
+    }
+}
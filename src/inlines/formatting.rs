@@ -1,10 +1,17 @@
 use crate::bloc::formatting::SynAntFormatterExt;
-use crate::format::{StringBuilderExt, ToEscaped, as_in, meaning};
+use crate::commands::FullMessageFormatter;
+use crate::format::{
+    MAX_MESSAGE_LEN, StringBuilderExt, ToEscaped, as_in, meaning, truncate_message,
+};
 use crate::{
     format::{LinksProvider, LookupFormatter},
-    stands4::{AbbreviationDefinition, PhraseDefinition, SynAntDefinitions, WordDefinition},
+    stands4::{
+        AbbreviationDefinition, PhraseDefinition, QuoteDefinition, SynAntDefinitions,
+        WordDefinition,
+    },
     urban::UrbanDefinition,
 };
+use crate::settings::ChatSettings;
 use std::string::FromUtf8Error;
 use teloxide::types::{
     InlineQueryResult, InlineQueryResultArticle, InputMessageContent, InputMessageContentText,
@@ -12,6 +19,11 @@ use teloxide::types::{
 };
 use teloxide::utils::markdown::escape;
 
+/// How many entries of a lookup are turned into inline answers at most.
+const MAX_INLINE_RESULTS: usize = 200;
+/// ID of the article sending all the definitions at once, set apart from the `answer-<i>` ones.
+pub const SEND_ALL_ID: &str = "answer-all";
+
 enum Desc {
     Building(string_builder::Builder),
     Done(Result<String, FromUtf8Error>),
@@ -150,10 +162,43 @@ impl InlineAnswer {
     }
 }
 
+/// A source the answers following it belong to, merged into a single article.
+struct Section {
+    source: String,
+    available: bool,
+    /// Index of the first answer of the section.
+    start: usize,
+}
+
 #[derive(Default)]
 pub struct InlineFormatter {
     answers: Vec<InlineAnswer>,
+    sections: Vec<Section>,
     link_provider: LinksProvider,
+    settings: ChatSettings,
+    /// Composes the message of the article sending all the definitions at once,
+    /// every visit is passed on to it.
+    full: FullMessageFormatter,
+    /// Where each visited definition of words, phrases, synonyms or Urban Dictionary ends
+    /// in the message of the article sending all of them, in bytes.
+    definition_ends: Vec<usize>,
+    /// Where the title ends in the message of the article sending all the definitions, in bytes.
+    title_end: usize,
+    /// Where the visited definitions can be read in full, linked when not all of them fit in a message.
+    source_link: Option<String>,
+    /// The looked up word, if the definitions are shown for its base form instead.
+    inflected: Option<String>,
+}
+
+impl InlineFormatter {
+    /// Notes the end of a definition just passed on to the full message, and where it came from
+    /// unless a link was already given.
+    fn visited_definition(&mut self, link: impl FnOnce(&LinksProvider) -> String) {
+        self.definition_ends.push(self.full.len());
+        if self.source_link.is_none() {
+            self.source_link = Some(link(&self.link_provider));
+        }
+    }
 }
 
 impl LookupFormatter for InlineFormatter {
@@ -187,10 +232,40 @@ impl LookupFormatter for InlineFormatter {
         &self.link_provider
     }
 
+    /// Access the settings of the user the answers are composed for.
+    fn settings(&self) -> &ChatSettings {
+        &self.settings
+    }
+
+    /// Composes the answers according to the user's settings, e.g. leaving the examples out
+    /// when the user prefers a compact output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let settings = ChatSettings { compact: true, ..Default::default() };
+    /// let fmt = InlineFormatter::default().with_settings(settings);
+    /// assert!(fmt.settings().compact);
+    /// ```
+    fn with_settings(self, settings: ChatSettings) -> Self {
+        InlineFormatter {
+            full: self.full.with_settings(settings.clone()),
+            settings,
+            ..self
+        }
+    }
+
+    /// Visits every entry up to `MAX_INLINE_RESULTS`, regardless of the user's settings,
+    /// since the inline answers are scrolled through in pages.
+    fn max_results(&self) -> usize {
+        MAX_INLINE_RESULTS
+    }
+
     /// Adds a word definition to the formatter's accumulated inline answers.
     ///
     /// The new answer's title is formatted as "#<index> - <term> (<part_of_speech>)" where an empty
-    /// part of speech is replaced with `"?"`. The answer's meaning is set from `def.definition`.
+    /// part of speech is replaced with `"?"`, followed by ", from <word>" if the term is the base form
+    /// of the looked up word. The answer's meaning is set from `def.definition`.
     /// If `def.example` is non-empty, it is appended as the answer's description.
     ///
     /// # Examples
@@ -208,15 +283,19 @@ impl LookupFormatter for InlineFormatter {
     /// assert_eq!(fmt.answers.len(), 1);
     /// ```
     fn visit_word(&mut self, i: usize, def: &WordDefinition) {
+        self.full.visit_word(i, def);
+        self.visited_definition(|links| links.word_link(&def.term));
         let part_of_speech = match def.part_of_speech.is_empty() {
             true => &"?".to_string(),
             false => &def.part_of_speech,
         };
 
-        let mut answer =
-            InlineAnswer::new(format!("#{} - {} ({})", i + 1, def.term, part_of_speech))
-                .meaning(def.definition.clone());
-        if !def.example.is_empty() {
+        let mut title = format!("#{} - {} ({})", i + 1, def.term, part_of_speech);
+        if let Some(word) = &self.inflected {
+            title.push_str(&format!(", from {}", word));
+        }
+        let mut answer = InlineAnswer::new(title).meaning(def.definition.clone());
+        if !def.example.is_empty() && !self.settings.compact {
             answer = answer.description(def.example.to_escaped());
         }
         self.answers.push(answer);
@@ -242,9 +321,11 @@ impl LookupFormatter for InlineFormatter {
     /// assert_eq!(fmt.answers[0].meaning.as_deref(), Some("an expression of good luck"));
     /// ```
     fn visit_phrase(&mut self, i: usize, def: &PhraseDefinition) {
+        self.full.visit_phrase(i, def);
+        self.visited_definition(|links| links.phrase_link(&def.term));
         let mut answer = InlineAnswer::new(format!("#{} - {}", i + 1, def.term))
             .meaning(def.explanation.clone());
-        if !def.example.is_empty() {
+        if !def.example.is_empty() && !self.settings.compact {
             answer = answer.description(as_in(&def.example.to_escaped()));
         }
 
@@ -267,6 +348,7 @@ impl LookupFormatter for InlineFormatter {
     /// // fmt.visit_abbreviations(0, "abbrs", &defs);
     /// ```
     fn visit_abbreviations(&mut self, i: usize, category: &str, defs: &[&AbbreviationDefinition]) {
+        self.full.visit_abbreviations(i, category, defs);
         let category = match category.len() {
             0 => "uncategorized".to_string(),
             _ => category.to_string(),
@@ -286,6 +368,37 @@ impl LookupFormatter for InlineFormatter {
         self.answers.push(answer);
     }
 
+    /// Creates and appends an InlineAnswer for an abbreviation category.
+    ///
+    /// The created answer's title is "#<index+1> in [<category>] (<count>)" and its meaning
+    /// lists the category's definitions separated by ", ", so that a category can be picked right from the results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut fmt = InlineFormatter::default();
+    /// let def = AbbreviationDefinition { definition: "As Soon As Possible".into(), category: "Other".into() };
+    /// fmt.visit_abbreviation_category(0, "Other", &[&def]);
+    /// assert_eq!(fmt.answers[0].title, "#1 in [Other] (1)");
+    /// ```
+    fn visit_abbreviation_category(
+        &mut self,
+        i: usize,
+        category: &str,
+        defs: &[&AbbreviationDefinition],
+    ) {
+        self.full.visit_abbreviation_category(i, category, defs);
+        let meaning = defs
+            .iter()
+            .map(|def| def.definition.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.answers.push(
+            InlineAnswer::new(format!("#{} in [{}] ({})", i + 1, category, defs.len()))
+                .meaning(meaning),
+        );
+    }
+
     /// Adds a synonym/antonym entry to the formatter as an inline answer.
     ///
     /// The created answer's title is "#{i} {term} [{part_of_speech}]" and its meaning is set
@@ -309,6 +422,8 @@ impl LookupFormatter for InlineFormatter {
     /// assert!(fmt.answers[0].title.contains("bright"));
     /// ```
     fn visit_syn_ant(&mut self, i: usize, def: &SynAntDefinitions) {
+        self.full.visit_syn_ant(i, def);
+        self.visited_definition(|links| links.syn_ant_link(&def.term));
         let mut description = string_builder::Builder::default();
         Self::push_syn_ant(&mut description, def, || {
             "Surprisingly, there are no synonyms or antonyms to this!".to_string()
@@ -341,9 +456,11 @@ impl LookupFormatter for InlineFormatter {
     /// assert!(fmt.answers[0].title.contains("yeet"));
     /// ```
     fn visit_urban_definition(&mut self, i: usize, def: &UrbanDefinition) {
+        self.full.visit_urban_definition(i, def);
+        self.visited_definition(|links| links.urban_link(&def.word));
         let mut answer =
             InlineAnswer::new(format!("#{} - {}", i + 1, def.word)).meaning(def.meaning.clone());
-        if let Some(example) = &def.example {
+        if let Some(example) = def.example.as_ref().filter(|_| !self.settings.compact) {
             answer = answer.description(as_in(&example.to_escaped()));
         }
         self.answers.push(answer);
@@ -364,6 +481,7 @@ impl LookupFormatter for InlineFormatter {
     /// fmt.visit_word_finder_definition(0, &"pattern".to_string());
     /// ```
     fn visit_word_finder_definition(&mut self, i: usize, def: String) {
+        self.full.visit_word_finder_definition(i, def.clone());
         let def = def.to_escaped();
         let mut answer = self
             .answers
@@ -380,29 +498,164 @@ impl LookupFormatter for InlineFormatter {
         self.answers.push(answer);
     }
 
-    /// Accepts a title but intentionally performs no action.
+    /// Adds a separate answer for a reverse-dictionary match.
+    ///
+    /// The answer is titled with the 1-based index and the candidate word, and carries the
+    /// definition as its meaning when one is known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut fmt = crate::inlines::formatting::InlineFormatter::default();
+    /// fmt.visit_reverse_match(0, "bibliophile", None);
+    /// ```
+    fn visit_reverse_match(&mut self, i: usize, word: &str, def: Option<&WordDefinition>) {
+        self.full.visit_reverse_match(i, word, def);
+        let mut answer = InlineAnswer::new(format!("#{} - {}", i + 1, word));
+        if let Some(def) = def {
+            answer = answer.meaning(def.definition.clone());
+        }
+        self.answers.push(answer);
+    }
+
+    /// Adds an answer telling that nothing was found for `word` and listing the closest correctly spelled words.
+    ///
+    /// The whole notice goes into the title, so that it is visible in the list of results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut fmt = crate::inlines::formatting::InlineFormatter::default();
+    /// fmt.visit_spelling_suggestions("recieve", &["receive".to_string()]);
+    /// ```
+    fn visit_spelling_suggestions(&mut self, word: &str, suggestions: &[String]) {
+        self.full.visit_spelling_suggestions(word, suggestions);
+        self.answers.push(InlineAnswer::new(format!(
+            "No results for {}. Did you mean: {}?",
+            word,
+            suggestions.join(", ")
+        )));
+    }
+
+    /// Notes that the results are shown for the base form `lemma` of the looked up `word`.
+    ///
+    /// Rather than taking an answer of its own, the note goes into the titles of the definitions visited next.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut fmt = crate::inlines::formatting::InlineFormatter::default();
+    /// fmt.visit_lemma("run", "running");
+    /// ```
+    fn visit_lemma(&mut self, lemma: &str, word: &str) {
+        self.full.visit_lemma(lemma, word);
+        self.inflected = Some(word.to_string());
+    }
+
+    /// Accepts a pronunciation, which is only shown in the article sending all the definitions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut fmt = InlineFormatter::default();
+    /// fmt.visit_pronunciation("hello", "həˈləʊ");
+    /// ```
+    fn visit_pronunciation(&mut self, word: &str, ipa: &str) {
+        self.full.visit_pronunciation(word, ipa);
+    }
+
+    /// Creates and appends an InlineAnswer for a word's etymology.
+    ///
+    /// The created answer's title is "#<index+1> - origin of <word>" and its meaning is the etymology itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut fmt = InlineFormatter::default();
+    /// fmt.visit_etymology(0, "run", "From Middle English rennen.");
+    /// assert_eq!(fmt.answers[0].title, "#1 - origin of run");
+    /// ```
+    fn visit_etymology(&mut self, i: usize, word: &str, etymology: &str) {
+        self.full.visit_etymology(i, word, etymology);
+        self.answers.push(
+            InlineAnswer::new(format!("#{} - origin of {}", i + 1, word))
+                .meaning(etymology.to_string()),
+        );
+    }
+
+    /// Creates and appends an InlineAnswer for a quote.
     ///
-    /// This method is a no-op placeholder; provided titles are ignored.
+    /// The created answer's title is "#<index+1> - <author>", or "#<index+1> - unknown author"
+    /// if the author is missing, and its meaning is the quote itself.
     ///
     /// # Examples
     ///
     /// ```
     /// let mut fmt = InlineFormatter::default();
-    /// fmt.append_title("ignored".to_string());
+    /// let def = QuoteDefinition {
+    ///     quote: "Fortune favors the bold.".into(),
+    ///     author: "Virgil".into(),
+    /// };
+    /// fmt.visit_quote(0, &def);
+    /// assert_eq!(fmt.answers[0].title, "#1 - Virgil");
+    /// ```
+    fn visit_quote(&mut self, i: usize, def: &QuoteDefinition) {
+        self.full.visit_quote(i, def);
+        let author = match def.author.is_empty() {
+            true => "unknown author",
+            false => &def.author,
+        };
+        self.answers.push(
+            InlineAnswer::new(format!("#{} - {}", i + 1, author)).meaning(def.quote.clone()),
+        );
+    }
+
+    /// Starts a section: every answer visited from now on until the next section is merged
+    /// into a single article titled after the source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut fmt = InlineFormatter::default();
+    /// fmt.visit_section("Urban Dictionary", false);
+    /// assert_eq!(fmt.build().unwrap().len(), 1);
+    /// ```
+    fn visit_section(&mut self, source: &str, available: bool) {
+        self.full.visit_section(source, available);
+        self.sections.push(Section {
+            source: source.to_string(),
+            available,
+            start: self.answers.len(),
+        });
+    }
+
+    /// Accepts a title, which is only shown in the article sending all the definitions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut fmt = InlineFormatter::default();
+    /// fmt.append_title("Found 2 definitions".to_string());
     /// ```
-    fn append_title(&mut self, _title: String) {
-        // no support for now
+    fn append_title(&mut self, title: String) {
+        self.full.append_title(title);
+        if self.definition_ends.is_empty() {
+            self.title_end = self.full.len();
+        }
     }
 
-    fn append_link(&mut self, _link: String) {
-        // no support for now
+    fn append_link(&mut self, link: String) {
+        self.full.append_link(link.clone());
+        self.source_link = Some(link);
     }
 
     /// Finalizes accumulated answers and converts them into inline query result articles.
     ///
     /// This consumes the formatter, finalizes each answer's description, composes the
     /// full MarkdownV2 message text for each answer, and wraps each as an
-    /// `InlineQueryResult::Article`.
+    /// `InlineQueryResult::Article`. When several definitions were visited, the articles start with
+    /// one sending them in a single message. If they don't all fit, it sends as many whole definitions
+    /// as do, along with a link to the rest, see [`fit_definitions`].
     ///
     /// # Examples
     ///
@@ -417,10 +670,26 @@ impl LookupFormatter for InlineFormatter {
     /// `Ok(Vec<InlineQueryResult>)` with one article per accumulated answer, or
     /// `Err(FromUtf8Error)` if finalizing any answer's description fails.
     fn build(self) -> Result<Self::Value, Self::Error> {
+        if !self.sections.is_empty() {
+            return compose_sections(self.sections, self.answers);
+        }
+        let mut results = Vec::with_capacity(self.answers.len() + 1);
+        if self.definition_ends.len() > 1 {
+            let total = self.definition_ends.len();
+            let full_text = self.full.build()?;
+            if let Some((full_text, count)) = fit_definitions(
+                full_text,
+                self.title_end,
+                &self.definition_ends,
+                self.source_link,
+            ) {
+                results.push(compose_send_all_result(count, total, full_text));
+            }
+        }
         self.answers
             .into_iter()
             .enumerate()
-            .try_fold(Vec::new(), |mut acc, (i, answer)| {
+            .try_fold(results, |mut acc, (i, answer)| {
                 let answer = answer.build_description();
                 let full_text = compose_inline_answer(&answer)?;
                 let article = compose_inline_result(i, &answer, full_text);
@@ -430,6 +699,66 @@ impl LookupFormatter for InlineFormatter {
     }
 }
 
+/// Merge the answers of every section into a single article per section.
+///
+/// The article is titled after the section's source, its description is the meaning of the first answer,
+/// or a note that the source is unavailable, and its message holds every answer of the section,
+/// cut down to Telegram's length limit with [`truncate_message`]. Answers visited before the first section are dropped.
+///
+/// # Examples
+///
+/// ```ignore
+/// let mut fmt = InlineFormatter::default();
+/// fmt.visit_section("Dictionary", true);
+/// fmt.visit_word(0, &def);
+/// fmt.visit_word(1, &def);
+/// assert_eq!(fmt.build().unwrap().len(), 1);
+/// ```
+fn compose_sections(
+    sections: Vec<Section>,
+    answers: Vec<InlineAnswer>,
+) -> Result<Vec<InlineQueryResult>, FromUtf8Error> {
+    let ends = sections
+        .iter()
+        .skip(1)
+        .map(|section| section.start)
+        .chain(std::iter::once(answers.len()))
+        .collect::<Vec<_>>();
+    let mut answers = answers.into_iter().map(InlineAnswer::build_description);
+    let mut consumed = 0;
+    let mut results = Vec::with_capacity(sections.len());
+    for (i, (section, end)) in sections.into_iter().zip(ends).enumerate() {
+        let skipped = section.start - consumed;
+        let section_answers = answers
+            .by_ref()
+            .skip(skipped)
+            .take(end - section.start)
+            .collect::<Vec<_>>();
+        consumed = end;
+
+        let mut full_text = string_builder::Builder::default();
+        full_text.append(format!("*{}*\n", escape(&section.source)));
+        let description = match section.available {
+            true => section_answers
+                .first()
+                .and_then(|answer| answer.meaning.clone()),
+            false => {
+                full_text.append("_Source unavailable_\n");
+                Some("Source unavailable".to_string())
+            }
+        };
+        for answer in &section_answers {
+            full_text.append("\n");
+            full_text.appendl(compose_inline_answer(answer)?);
+        }
+        let mut answer = InlineAnswer::new(section.source);
+        answer.meaning = description;
+        let full_text = truncate_message(full_text.string()?);
+        results.push(compose_inline_result(i, &answer, full_text));
+    }
+    Ok(results)
+}
+
 /// Compose the MarkdownV2-formatted message text for an inline answer.
 ///
 /// The resulting string begins with the escaped title. If `meaning` is present, it is
@@ -495,3 +824,169 @@ fn compose_inline_result(i: usize, answer: &InlineAnswer, full_text: String) ->
     }
     InlineQueryResult::Article(article)
 }
+
+/// Fits the message sending all the definitions into Telegram's length limit.
+///
+/// A message that is too long keeps only the whole definitions fitting along with a link to `source_link`,
+/// `ends` telling where each definition ends in the message. Its title, ending at `title_end`, is left out then,
+/// as it counts every definition rather than the ones kept.
+///
+/// # Examples
+///
+/// ```ignore
+/// let text = "Found 2 definitions\n\n#1 first\n\n#2 second\n\n".to_string();
+/// let (fitted, count) = fit_definitions(text.clone(), 21, &[30, 42], None).unwrap();
+/// assert_eq!((fitted, count), (text, 2));
+/// ```
+///
+/// # Returns
+///
+/// `Some((message, count))` with the message and how many definitions it holds,
+/// or `None` if not even the first definition fits.
+fn fit_definitions(
+    text: String,
+    title_end: usize,
+    ends: &[usize],
+    source_link: Option<String>,
+) -> Option<(String, usize)> {
+    if text.encode_utf16().count() <= MAX_MESSAGE_LEN {
+        return Some((text, ends.len()));
+    }
+    let footer = source_link
+        .map(|link| format!("Check out other definitions at {}\n\n", link))
+        .unwrap_or_default();
+    let room = MAX_MESSAGE_LEN.saturating_sub(footer.encode_utf16().count());
+    let count = ends
+        .iter()
+        .take_while(|&&end| text[title_end..end].encode_utf16().count() <= room)
+        .count();
+    let end = *ends[..count].last()?;
+    Some((format!("{}{}", &text[title_end..end], footer), count))
+}
+
+/// Builds the article sending `count` of the `total` definitions at once, with `full_text` as its message.
+///
+/// # Examples
+///
+/// ```
+/// let res = compose_send_all_result(7, 7, "*Found 7 definitions*".to_string());
+/// assert!(matches!(res, InlineQueryResult::Article(article) if article.title == "Send all 7 definitions"));
+/// let res = compose_send_all_result(5, 7, "*Found 7 definitions*".to_string());
+/// assert!(matches!(res, InlineQueryResult::Article(article) if article.title == "Send 5 of 7 definitions"));
+/// ```
+fn compose_send_all_result(count: usize, total: usize, full_text: String) -> InlineQueryResult {
+    let content = InputMessageContentText::new(full_text).parse_mode(ParseMode::MarkdownV2);
+    let content = InputMessageContent::Text(content);
+    let (title, description) = match count == total {
+        true => (
+            format!("Send all {} definitions", total),
+            "Every definition below in a single message",
+        ),
+        false => (
+            format!("Send {} of {} definitions", count, total),
+            "As many definitions as fit in a single message",
+        ),
+    };
+    let article =
+        InlineQueryResultArticle::new(SEND_ALL_ID, title, content).description(description);
+    InlineQueryResult::Article(article)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format::MAX_MESSAGE_LEN;
+
+    fn definition(definition: &str) -> WordDefinition {
+        WordDefinition {
+            term: "run".to_string(),
+            definition: definition.to_string(),
+            example: String::new(),
+            part_of_speech: "verb".to_string(),
+        }
+    }
+
+    fn title(result: &InlineQueryResult) -> &str {
+        match result {
+            InlineQueryResult::Article(article) => &article.title,
+            _ => panic!("expected an article, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn several_definitions_start_with_send_all() {
+        let mut fmt = InlineFormatter::default();
+        fmt.append_title("Found 2 definitions".to_string());
+        fmt.visit_word(0, &definition("move swiftly on foot"));
+        fmt.visit_word(1, &definition("be in charge of"));
+        let results = fmt.build().unwrap();
+        assert_eq!(results.len(), 3);
+        assert_eq!(title(&results[0]), "Send all 2 definitions");
+        assert_eq!(title(&results[1]), "#1 - run (verb)");
+    }
+
+    #[test]
+    fn send_all_counts_only_what_fits() {
+        let mut fmt = InlineFormatter::default();
+        fmt.append_title("Found 10 definitions".to_string());
+        let long = "a".repeat(MAX_MESSAGE_LEN / 4);
+        for i in 0..10 {
+            fmt.visit_word(i, &definition(&long));
+        }
+        let results = fmt.build().unwrap();
+        assert_eq!(results.len(), 11);
+        assert_eq!(title(&results[0]), "Send 3 of 10 definitions");
+        let InlineQueryResult::Article(article) = &results[0] else {
+            panic!("expected an article");
+        };
+        let InputMessageContent::Text(content) = &article.input_message_content else {
+            panic!("expected a text message");
+        };
+        assert!(content.message_text.encode_utf16().count() <= MAX_MESSAGE_LEN);
+        assert!(content.message_text.contains("\\#3 \\- run"));
+        assert!(!content.message_text.contains("\\#4 \\- run"));
+        assert!(!content.message_text.contains("Found 10 definitions"));
+        assert!(content.message_text.starts_with("\\#1 \\- run"));
+        assert!(content.message_text.ends_with(&format!(
+            "Check out other definitions at {}\n\n",
+            LinksProvider::default().word_link("run")
+        )));
+    }
+
+    #[test]
+    fn single_definition_has_no_send_all() {
+        let mut fmt = InlineFormatter::default();
+        fmt.visit_word(0, &definition("move swiftly on foot"));
+        let results = fmt.build().unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(title(&results[0]), "#1 - run (verb)");
+    }
+
+    #[test]
+    fn long_sections_are_cut_to_message_limit() {
+        let mut fmt = InlineFormatter::default();
+        fmt.visit_section("Dictionary", true);
+        for i in 0..3 {
+            fmt.visit_word(i, &definition(&"run ".repeat(600)));
+        }
+        let results = fmt.build().unwrap();
+        let text = match &results[0] {
+            InlineQueryResult::Article(article) => match &article.input_message_content {
+                InputMessageContent::Text(text) => &text.message_text,
+                content => panic!("expected a text, got {:?}", content),
+            },
+            result => panic!("expected an article, got {:?}", result),
+        };
+        assert!(text.encode_utf16().count() <= MAX_MESSAGE_LEN);
+    }
+
+    #[test]
+    fn lemma_note_goes_into_titles() {
+        let mut fmt = InlineFormatter::default();
+        fmt.visit_lemma("run", "running");
+        fmt.visit_word(0, &definition("move swiftly on foot"));
+        let results = fmt.build().unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(title(&results[0]), "#1 - run (verb), from running");
+    }
+}
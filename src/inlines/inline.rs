@@ -1,25 +1,52 @@
+use crate::bloc::abbr_lookup::AbbrLookupHandler;
+use crate::bloc::all_lookup::AllLookupHandler;
 use crate::bloc::common::CommandHandler;
 use crate::bloc::phrase_lookup::PhraseLookupHandler;
+use crate::bloc::quote_lookup::QuoteLookupHandler;
 use crate::bloc::suggestions::SuggestionsHandler;
 use crate::bloc::thesaurus_lookup::ThesaurusLookupHandler;
 use crate::bloc::urban_lookup::UrbanLookupHandler;
-use crate::bloc::word_finder::WordFinderHandler;
+use crate::bloc::word_finder::{WordFinderHandler, looks_like_mask};
 use crate::bloc::word_lookup::WordLookupHandler;
-use crate::bot::InlineBot;
-use crate::inlines::debounce_inline_queries;
+use crate::bot::{InlineBot, LookupBotX};
+use crate::inlines::{InlinePages, debounce_inline_queries};
+use crate::settings::SettingsStore;
+use crate::text::{WORD_CHARS, normalize};
 use regex::Regex;
 use std::sync::LazyLock;
 use teloxide::{
     Bot,
     dispatching::UpdateFilterExt,
     prelude::{InlineQuery, Update},
+    types::InlineQueryResult,
 };
 
-static TEXT_PATTERN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^([a-z_ ]+)$").unwrap());
-static URBAN_PATTER: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(u)\.([a-z ]+)$").unwrap());
-static SYNO_PATTER: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(sa)\.([a-z]+)$").unwrap());
-static FINDER_PATTER: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^(f)\.([a-z_]+(?:, *[a-z]*)?)$").unwrap());
+static TEXT_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!(r"^([{WORD_CHARS}_*#@\[\] ]+)$")).unwrap());
+static URBAN_PATTER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!(r"^(u)\.([{WORD_CHARS} ]+)$")).unwrap());
+static QUOTE_PATTER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!(r"^(q)\.([{WORD_CHARS} ]+)$")).unwrap());
+static ABBR_PATTER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!(r"^(ab)\.([{WORD_CHARS}.& ]+)$")).unwrap());
+static ALL_PATTER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!(r"^(all)\.([{WORD_CHARS} ]+)$")).unwrap());
+static SYNO_PATTER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!(r"^(sa)\.([{WORD_CHARS}]+)$")).unwrap());
+static FINDER_PATTER: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"^(f)\.([{WORD_CHARS}_*#@\[\]]+(?:, *[{WORD_CHARS}]*)?(?: +[a-z_]+=[a-z0-9.]*)*)$"
+    ))
+    .unwrap()
+});
+static CLUE_PATTER: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"^(c)\.([{WORD_CHARS}_*#@\[\]]+, *[{WORD_CHARS}][{WORD_CHARS} ]*)$"
+    ))
+    .unwrap()
+});
+static WORD_POS_PATTER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!(r"^([{WORD_CHARS}]+ *: *[a-z]+)$")).unwrap());
 #[derive(Debug, Clone, PartialEq)]
 pub enum QueryCommands {
     Suggestions,
@@ -27,26 +54,37 @@ pub enum QueryCommands {
     PhraseLookup(String),
     UrbanLookup(String),
     ThesaurusLookup(String),
+    QuoteLookup(String),
+    AbbrLookup(String),
+    AllLookup(String),
     Finder(String),
+    Clue(String),
 }
 
 enum CommandTag {
     Urban,
     Thesaurus,
+    Quote,
+    Abbr,
+    All,
     Finder,
+    Clue,
 }
 
 impl CommandTag {
     /// Converts a short string tag into a corresponding `CommandTag`.
     ///
-    /// Recognizes the tags `"u"`, `"sa"`, and `"f"` and maps them to `Urban`, `Thesaurus`,
-    /// and `Finder` respectively. Any other input yields `None`.
+    /// Recognizes the tags `"u"`, `"sa"`, `"q"`, `"ab"`, `"all"`, `"f"` and `"c"` and maps them to `Urban`,
+    /// `Thesaurus`, `Quote`, `Abbr`, `All`, `Finder` and `Clue` respectively. Any other input yields `None`.
     ///
     /// # Examples
     ///
     /// ```
     /// assert_eq!(CommandTag::from("u"), Some(CommandTag::Urban));
     /// assert_eq!(CommandTag::from("sa"), Some(CommandTag::Thesaurus));
+    /// assert_eq!(CommandTag::from("q"), Some(CommandTag::Quote));
+    /// assert_eq!(CommandTag::from("ab"), Some(CommandTag::Abbr));
+    /// assert_eq!(CommandTag::from("all"), Some(CommandTag::All));
     /// assert_eq!(CommandTag::from("f"), Some(CommandTag::Finder));
     /// assert_eq!(CommandTag::from("x"), None);
     /// ```
@@ -54,7 +92,11 @@ impl CommandTag {
         match str.into().as_str() {
             "u" => Some(CommandTag::Urban),
             "sa" => Some(CommandTag::Thesaurus),
+            "q" => Some(CommandTag::Quote),
+            "ab" => Some(CommandTag::Abbr),
+            "all" => Some(CommandTag::All),
             "f" => Some(CommandTag::Finder),
+            "c" => Some(CommandTag::Clue),
             _ => None,
         }
     }
@@ -62,17 +104,23 @@ impl CommandTag {
 
 /// Determine the inline command represented by the given query string.
 ///
-/// Parses the provided lowercase-insensitive query and maps it to a `QueryCommands` variant:
+/// Parses the provided query, normalized the same way as messages (see [`normalize`]), and maps it to a `QueryCommands` variant:
 /// - empty input → `Suggestions`
 /// - prefixed forms:
 ///   - `u.<text>` → `UrbanLookup(text)`
 ///   - `sa.<text>` → `ThesaurusLookup(text)`
+///   - `q.<text>` → `QuoteLookup(text)`
+///   - `ab.<text>` → `AbbrLookup(text)`
+///   - `all.<text>` → `AllLookup(text)`
 ///   - `f.<text>` → `Finder(text)`
 /// - unprefixed forms:
 ///   - a single word → `WordLookup(word)`
+///   - a word with a part of speech after a colon, like `run:v` → `WordLookup("run:v")`
 ///   - multiple words → `PhraseLookup(phrase)`
 /// - inputs containing underscores are treated as `Finder`.
 ///
+/// Words may contain letters of any script, apostrophes and hyphens, e.g. `café` or `o'clock`.
+///
 /// # Returns
 ///
 /// `Some(QueryCommands::...)` with the parsed command, or `None` if the query does not match the recognized patterns.
@@ -87,16 +135,20 @@ impl CommandTag {
 /// assert!(matches!(extract_command("look".into()), Some(QueryCommands::WordLookup(p)) if p == "look"));
 /// assert!(matches!(extract_command("turn down".into()), Some(QueryCommands::PhraseLookup(p)) if p == "turn down"));
 /// ```
-fn extract_command(query: String) -> Option<QueryCommands> {
+pub(super) fn extract_command(query: String) -> Option<QueryCommands> {
     if query.is_empty() {
         return Some(QueryCommands::Suggestions);
     }
 
-    let query = query.to_lowercase();
+    let query = normalize(&query);
     URBAN_PATTER
         .captures(&query)
         .or_else(|| SYNO_PATTER.captures(&query))
+        .or_else(|| QUOTE_PATTER.captures(&query))
+        .or_else(|| ABBR_PATTER.captures(&query))
+        .or_else(|| ALL_PATTER.captures(&query))
         .or_else(|| FINDER_PATTER.captures(&query))
+        .or_else(|| CLUE_PATTER.captures(&query))
         .and_then(|captures| {
             let tag = captures.get(1)?.as_str();
             let tag = CommandTag::from(tag)?;
@@ -106,12 +158,21 @@ fn extract_command(query: String) -> Option<QueryCommands> {
         .map(|(tag, input)| match tag {
             CommandTag::Urban => QueryCommands::UrbanLookup(input.to_owned()),
             CommandTag::Thesaurus => QueryCommands::ThesaurusLookup(input.to_owned()),
+            CommandTag::Quote => QueryCommands::QuoteLookup(input.to_owned()),
+            CommandTag::Abbr => QueryCommands::AbbrLookup(input.to_owned()),
+            CommandTag::All => QueryCommands::AllLookup(input.to_owned()),
             CommandTag::Finder => QueryCommands::Finder(input.to_owned()),
+            CommandTag::Clue => QueryCommands::Clue(input.to_owned()),
+        })
+        .or_else(|| {
+            let input = WORD_POS_PATTER.captures(&query)?;
+            let input = input.get(1)?.as_str();
+            Some(QueryCommands::WordLookup(input.to_owned()))
         })
         .or_else(|| {
             let input = TEXT_PATTERN.captures(&query)?;
             let input = input.get(1)?.as_str();
-            if input.contains("_") {
+            if looks_like_mask(input) {
                 return Some(QueryCommands::Finder(input.to_owned()));
             }
 
@@ -131,7 +192,7 @@ fn extract_command(query: String) -> Option<QueryCommands> {
 /// Create a CommandHandler that processes inline queries, debounces them, and routes parsed commands to their respective inline handlers.
 ///
 /// The handler filters updates for inline queries, converts each query into a `QueryCommands` variant, wraps it in an `InlineBot`,
-/// applies `debounce_inline_queries`, and dispatches to the matching handler (suggestions, word lookup, phrase lookup, urban lookup,
+/// answers the next pages of a query from its kept results, applies `debounce_inline_queries`, and dispatches to the matching handler (suggestions, word lookup, phrase lookup, urban lookup,
 /// thesaurus lookup, or finder).
 ///
 /// # Examples
@@ -144,7 +205,22 @@ fn extract_command(query: String) -> Option<QueryCommands> {
 pub fn inlines_tree() -> CommandHandler {
     Update::filter_inline_query()
         .filter_map(|InlineQuery { query, .. }: InlineQuery| extract_command(query))
-        .map(|bot: Bot, query: InlineQuery| InlineBot { bot, query })
+        .map(
+            |bot: Bot, query: InlineQuery, settings: SettingsStore, pages: InlinePages| InlineBot {
+                // inline queries aren't bound to a chat, so the user's private chat settings apply
+                settings: settings.get(query.from.id.into()),
+                pages,
+                bot,
+                query,
+            },
+        )
+        .branch(
+            teloxide::dptree::filter_map(|bot: InlineBot| bot.kept_results()).endpoint(
+                |bot: InlineBot, results: Vec<InlineQueryResult>| async move {
+                    bot.respond(results).await
+                },
+            ),
+        )
         .filter_async(debounce_inline_queries)
         .branch(
             teloxide::dptree::case![QueryCommands::Suggestions]
@@ -166,10 +242,26 @@ pub fn inlines_tree() -> CommandHandler {
             teloxide::dptree::case![QueryCommands::ThesaurusLookup(phrase)]
                 .branch(InlineBot::thesaurus_lookup_handler()),
         )
+        .branch(
+            teloxide::dptree::case![QueryCommands::QuoteLookup(query)]
+                .branch(InlineBot::quote_lookup_handler()),
+        )
+        .branch(
+            teloxide::dptree::case![QueryCommands::AbbrLookup(query)]
+                .branch(InlineBot::abbr_lookup_handler()),
+        )
+        .branch(
+            teloxide::dptree::case![QueryCommands::AllLookup(query)]
+                .branch(InlineBot::all_lookup_handler()),
+        )
         .branch(
             teloxide::dptree::case![QueryCommands::Finder(phrase)]
                 .branch(InlineBot::word_finder_handler()),
         )
+        .branch(
+            teloxide::dptree::case![QueryCommands::Clue(clue)]
+                .branch(InlineBot::clue_finder_handler()),
+        )
 }
 
 #[cfg(test)]
@@ -208,6 +300,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn q_displays_quotes() {
+        let cmd = extract_command("q.mark twain".to_owned());
+        assert_eq!(
+            cmd,
+            Some(QueryCommands::QuoteLookup("mark twain".to_owned()))
+        );
+    }
+
+    #[test]
+    fn ab_displays_abbreviations() {
+        let cmd = extract_command("ab.a.k.a".to_owned());
+        assert_eq!(cmd, Some(QueryCommands::AbbrLookup("a.k.a".to_owned())));
+        let cmd = extract_command("ab.asap business".to_owned());
+        assert_eq!(
+            cmd,
+            Some(QueryCommands::AbbrLookup("asap business".to_owned()))
+        );
+    }
+
+    #[test]
+    fn all_displays_every_source() {
+        let cmd = extract_command("all.cookie".to_owned());
+        assert_eq!(cmd, Some(QueryCommands::AllLookup("cookie".to_owned())));
+    }
+
+    #[test]
+    fn colon_narrows_word_lookup() {
+        let cmd = extract_command("run:v".to_owned());
+        assert_eq!(cmd, Some(QueryCommands::WordLookup("run:v".to_owned())));
+        let cmd = extract_command("Run: Verb".to_owned());
+        assert_eq!(cmd, Some(QueryCommands::WordLookup("run: verb".to_owned())));
+    }
+
     #[test]
     fn f_displays_finder() {
         let cmd = extract_command("f.f__der".to_owned());
@@ -243,6 +369,25 @@ mod tests {
         let cmd = extract_command("look".to_owned());
         assert_eq!(cmd, Some(QueryCommands::WordLookup("look".to_owned())));
     }
+
+    #[test]
+    fn accented_word_displays_word_lookup() {
+        let cmd = extract_command("Cafe\u{301}".to_owned());
+        assert_eq!(cmd, Some(QueryCommands::WordLookup("café".to_owned())));
+        let cmd = extract_command("u.naïve".to_owned());
+        assert_eq!(cmd, Some(QueryCommands::UrbanLookup("naïve".to_owned())));
+    }
+
+    #[test]
+    fn apostrophes_and_hyphens_display_word_lookup() {
+        let cmd = extract_command("o\u{2019}clock".to_owned());
+        assert_eq!(cmd, Some(QueryCommands::WordLookup("o'clock".to_owned())));
+        let cmd = extract_command("well-being".to_owned());
+        assert_eq!(
+            cmd,
+            Some(QueryCommands::WordLookup("well-being".to_owned()))
+        );
+    }
     #[test]
     fn multiple_words_display_phrase_lookup() {
         let cmd = extract_command("turn down".to_owned());
@@ -251,4 +396,55 @@ mod tests {
             Some(QueryCommands::PhraseLookup("turn down".to_owned()))
         );
     }
+
+    #[test]
+    fn f_options_display_finder() {
+        let cmd = extract_command("f.f__der, xxx sort=alpha min_freq=1".to_owned());
+        assert_eq!(
+            cmd,
+            Some(QueryCommands::Finder(
+                "f__der, xxx sort=alpha min_freq=1".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn wildcards_display_finder() {
+        let cmd = extract_command("un*[ai]ble".to_owned());
+        assert_eq!(cmd, Some(QueryCommands::Finder("un*[ai]ble".to_owned())));
+
+        let cmd = extract_command("f.c@#".to_owned());
+        assert_eq!(cmd, Some(QueryCommands::Finder("c@#".to_owned())));
+    }
+
+    #[test]
+    fn mask_symbols_alone_display_lookup() {
+        let cmd = extract_command("ask @alice".to_owned());
+        assert_eq!(
+            cmd,
+            Some(QueryCommands::PhraseLookup("ask @alice".to_owned()))
+        );
+        let cmd = extract_command("#throwback".to_owned());
+        assert_eq!(
+            cmd,
+            Some(QueryCommands::WordLookup("#throwback".to_owned()))
+        );
+        let cmd = extract_command("john@example".to_owned());
+        assert_eq!(
+            cmd,
+            Some(QueryCommands::WordLookup("john@example".to_owned()))
+        );
+    }
+
+    #[test]
+    fn c_displays_clue() {
+        let cmd = extract_command("c.c_t, feline pet".to_owned());
+        assert_eq!(cmd, Some(QueryCommands::Clue("c_t, feline pet".to_owned())));
+    }
+
+    #[test]
+    fn c_without_hint_displays_nothing() {
+        let cmd = extract_command("c.c_t".to_owned());
+        assert_eq!(cmd, None);
+    }
 }
@@ -1,6 +1,10 @@
+mod chosen;
 mod debouncer;
 pub mod formatting;
 mod inline;
+mod pages;
 
+pub use chosen::*;
 pub use debouncer::*;
 pub use inline::*;
+pub use pages::*;
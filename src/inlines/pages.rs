@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use teloxide::types::{InlineQueryResult, UserId};
+use tokio::time::Instant;
+
+/// Telegram accepts at most this many results in a single answer to an inline query.
+pub const PAGE_SIZE: usize = 50;
+/// How long the results of a query are kept for its next pages.
+const PAGES_TTL: Duration = Duration::from_secs(5 * 60);
+
+/// Splits the page starting at `offset` off the results.
+///
+/// The offset is the one Telegram sends with the query, empty for the first page.
+///
+/// # Returns
+///
+/// The results of the page and the offset of the next one, which is empty if there are no more pages.
+///
+/// # Examples
+///
+/// ```
+/// let (page, next_offset) = page_of((0..120).collect::<Vec<_>>(), "50");
+/// assert_eq!(page.len(), 50);
+/// assert_eq!(next_offset, "100");
+/// ```
+pub fn page_of<T>(results: Vec<T>, offset: &str) -> (Vec<T>, String) {
+    let offset = offset.parse::<usize>().unwrap_or(0);
+    let total = results.len();
+    let page = results.into_iter().skip(offset).take(PAGE_SIZE).collect();
+    let next = offset + PAGE_SIZE;
+    let next_offset = match next < total {
+        true => next.to_string(),
+        false => String::new(),
+    };
+    (page, next_offset)
+}
+
+/// The results of a query kept along with the moment they were kept at.
+type KeptResults = (Instant, Vec<InlineQueryResult>);
+
+/// The results of the recent inline queries, so that scrolling to their next pages doesn't look them up again.
+///
+/// The results are kept per user and query text for `PAGES_TTL`.
+#[derive(Debug, Clone, Default)]
+pub struct InlinePages {
+    results: Arc<Mutex<HashMap<(UserId, String), KeptResults>>>,
+}
+
+impl InlinePages {
+    /// Returns the results of the user's query, unless they have expired or have never been kept.
+    pub fn get(&self, user: UserId, query: &str) -> Option<Vec<InlineQueryResult>> {
+        let results = self.results.lock().unwrap();
+        let (kept_at, results) = results.get(&(user, query.to_string()))?;
+        (kept_at.elapsed() < PAGES_TTL).then(|| results.clone())
+    }
+
+    /// Keeps the results of the user's query, forgetting the expired results of every query.
+    ///
+    /// Results fitting into a single page aren't kept, as there's nothing to scroll to.
+    pub fn insert(&self, user: UserId, query: &str, results: &[InlineQueryResult]) {
+        let mut kept = self.results.lock().unwrap();
+        kept.retain(|_, (kept_at, _)| kept_at.elapsed() < PAGES_TTL);
+        if results.len() > PAGE_SIZE {
+            kept.insert(
+                (user, query.to_string()),
+                (Instant::now(), results.to_vec()),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use teloxide::types::{InlineQueryResultArticle, InputMessageContent, InputMessageContentText};
+
+    /// Makes `count` distinct article results.
+    fn articles(count: usize) -> Vec<InlineQueryResult> {
+        (0..count)
+            .map(|i| {
+                InlineQueryResult::Article(InlineQueryResultArticle::new(
+                    i.to_string(),
+                    i.to_string(),
+                    InputMessageContent::Text(InputMessageContentText::new(i.to_string())),
+                ))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn several_pages_are_kept() {
+        let pages = InlinePages::default();
+        pages.insert(UserId(1), "run", &articles(60));
+        assert_eq!(pages.get(UserId(1), "run"), Some(articles(60)));
+        assert_eq!(pages.get(UserId(2), "run"), None);
+        assert_eq!(pages.get(UserId(1), "walk"), None);
+    }
+
+    #[test]
+    fn single_page_is_not_kept() {
+        let pages = InlinePages::default();
+        pages.insert(UserId(1), "run", &articles(PAGE_SIZE));
+        assert_eq!(pages.get(UserId(1), "run"), None);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn kept_results_expire() {
+        let pages = InlinePages::default();
+        pages.insert(UserId(1), "run", &articles(60));
+        tokio::time::advance(PAGES_TTL - Duration::from_secs(1)).await;
+        assert!(pages.get(UserId(1), "run").is_some());
+        tokio::time::advance(Duration::from_secs(1)).await;
+        assert_eq!(pages.get(UserId(1), "run"), None);
+        pages.insert(UserId(2), "walk", &articles(60));
+        assert_eq!(pages.results.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn pages_follow_one_another() {
+        let results = (0..120).collect::<Vec<_>>();
+        let (page, next_offset) = page_of(results.clone(), "");
+        assert_eq!(page, (0..50).collect::<Vec<_>>());
+        assert_eq!(next_offset, "50");
+        let (page, next_offset) = page_of(results, "100");
+        assert_eq!(page, (100..120).collect::<Vec<_>>());
+        assert_eq!(next_offset, "");
+    }
+
+    #[test]
+    fn single_page_has_no_next_offset() {
+        let (page, next_offset) = page_of(vec![1, 2, 3], "");
+        assert_eq!(page, vec![1, 2, 3]);
+        assert_eq!(next_offset, "");
+    }
+}
@@ -0,0 +1,178 @@
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+/// Shortest stem a suffix may be stripped down to, so that e.g. "as" is left alone.
+const MIN_STEM_LEN: usize = 2;
+const VOWELS: &str = "aeiou";
+/// Consonants that are commonly doubled in the base form itself, e.g. "fall" or "pass".
+const DOUBLED_IN_BASE: &str = "lsfz";
+/// Endings after which the plural/3rd person suffix is "-es" rather than "-s".
+const SIBILANTS: [&str; 5] = ["s", "x", "z", "ch", "sh"];
+
+static IRREGULAR_FORMS: LazyLock<HashMap<&'static str, &'static str>> = LazyLock::new(|| {
+    include_str!("irregular_forms.txt")
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split_once(' '))
+        .collect()
+});
+
+/// Guesses the base forms (lemmas) an inflected English word may come from.
+///
+/// Irregular forms are looked up in a table first, e.g. "geese" gives "goose" and "better" gives "good".
+/// Otherwise regular inflectional suffixes ("-s", "-es", "-ies", "-ves", "-ed", "-ing", "-er", "-est")
+/// are stripped, undoing the spelling changes they cause, like doubled consonants or a dropped "e".
+/// Since the rules can't tell "baking" from "walking", up to two candidates are given, the likelier first.
+///
+/// Only single words made of latin letters are handled; anything else yields no candidates.
+///
+/// # Returns
+///
+/// The candidate base forms, lowercase and distinct from the word itself; empty if the word doesn't look inflected.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(base_forms("running"), vec!["run".to_string(), "runn".to_string()]);
+/// assert_eq!(base_forms("geese"), vec!["goose".to_string()]);
+/// assert!(base_forms("run").is_empty());
+/// ```
+pub fn base_forms(word: &str) -> Vec<String> {
+    let word = word.trim().to_lowercase();
+    if word.is_empty() || !word.chars().all(|c| c.is_ascii_alphabetic()) {
+        return vec![];
+    }
+    if let Some(base) = IRREGULAR_FORMS.get(word.as_str()) {
+        return vec![base.to_string()];
+    }
+
+    let mut forms = strip_suffix(&word);
+    forms.retain(|form| form != &word && form.len() >= MIN_STEM_LEN);
+    forms.dedup();
+    forms
+}
+
+/// Applies the first suffix rule matching the word, most specific suffixes first.
+fn strip_suffix(word: &str) -> Vec<String> {
+    let stem_of = |suffix: &str| {
+        word.strip_suffix(suffix)
+            .filter(|stem| stem.len() >= MIN_STEM_LEN)
+            .map(str::to_string)
+    };
+
+    if let Some(stem) = stem_of("ies") {
+        return vec![format!("{}y", stem), format!("{}ie", stem)];
+    }
+    if let Some(stem) = stem_of("ves") {
+        return vec![format!("{}f", stem), format!("{}fe", stem)];
+    }
+    if let Some(stem) = stem_of("es")
+        && SIBILANTS.iter().any(|sibilant| stem.ends_with(sibilant))
+    {
+        return vec![stem, word[..word.len() - 1].to_string()];
+    }
+    if let Some(stem) = stem_of("s")
+        && !["s", "u", "i"].iter().any(|ending| stem.ends_with(ending))
+    {
+        return vec![stem];
+    }
+    for suffix in ["ing", "ed", "est", "er"] {
+        if let Some(stem) = stem_of(suffix) {
+            return restore_stem(stem, suffix);
+        }
+    }
+    vec![]
+}
+
+/// Undoes the spelling changes caused by appending a vowel-initial suffix to the base form.
+fn restore_stem(stem: String, suffix: &str) -> Vec<String> {
+    let chars: Vec<char> = stem.chars().collect();
+    let last = chars[chars.len() - 1];
+    let before_last = chars[chars.len() - 2];
+
+    // "running" -> "run", but "falling" -> "fall"
+    if last == before_last && !VOWELS.contains(last) {
+        let undoubled = stem[..stem.len() - 1].to_string();
+        return match DOUBLED_IN_BASE.contains(last) {
+            true => vec![stem, undoubled],
+            false => vec![undoubled, stem],
+        };
+    }
+    // "tried" -> "try", "happier" -> "happy"; "-ying" keeps the "y" as in "trying"
+    if last == 'i' && suffix != "ing" {
+        return vec![format!("{}y", &stem[..stem.len() - 1])];
+    }
+    // "baking" -> "bake" is likelier than "bak", while "walking" -> "walk" is likelier than "walke"
+    let with_e = format!("{}e", stem);
+    match ends_with_cvc(&chars) {
+        true => vec![with_e, stem],
+        false => vec![stem, with_e],
+    }
+}
+
+/// Checks whether the stem ends with consonant-vowel-consonant, like "bak" or "hop".
+fn ends_with_cvc(chars: &[char]) -> bool {
+    let is_vowel = |c: &char| VOWELS.contains(*c);
+    match chars {
+        [.., a, b, c] => !is_vowel(a) && is_vowel(b) && !is_vowel(c) && !"wxy".contains(*c),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn forms(word: &str) -> Vec<String> {
+        base_forms(word)
+    }
+
+    #[test]
+    fn irregular_forms_are_looked_up() {
+        assert_eq!(forms("geese"), vec!["goose"]);
+        assert_eq!(forms("better"), vec!["good"]);
+        assert_eq!(forms("went"), vec!["go"]);
+        assert_eq!(forms("Children"), vec!["child"]);
+    }
+
+    #[test]
+    fn doubled_consonants_are_undoubled() {
+        assert_eq!(forms("running"), vec!["run", "runn"]);
+        assert_eq!(forms("bigger"), vec!["big", "bigg"]);
+        assert_eq!(forms("stopped"), vec!["stop", "stopp"]);
+        assert_eq!(forms("falling"), vec!["fall", "fal"]);
+    }
+
+    #[test]
+    fn dropped_e_is_restored() {
+        assert_eq!(forms("baking"), vec!["bake", "bak"]);
+        assert_eq!(forms("walking"), vec!["walk", "walke"]);
+        assert_eq!(forms("nicest"), vec!["nice", "nic"]);
+    }
+
+    #[test]
+    fn y_is_restored() {
+        assert_eq!(forms("tried"), vec!["try"]);
+        assert_eq!(forms("happier"), vec!["happy"]);
+        assert_eq!(forms("flies"), vec!["fly", "flie"]);
+        assert_eq!(forms("trying"), vec!["try", "trye"]);
+    }
+
+    #[test]
+    fn plurals_are_stripped() {
+        assert_eq!(forms("cats"), vec!["cat"]);
+        assert_eq!(forms("boxes"), vec!["box", "boxe"]);
+        assert_eq!(forms("wolves"), vec!["wolf", "wolfe"]);
+    }
+
+    #[test]
+    fn non_inflected_words_are_left_alone() {
+        assert!(forms("run").is_empty());
+        assert!(forms("glass").is_empty());
+        assert!(forms("bus").is_empty());
+        assert!(forms("as").is_empty());
+        assert!(forms("ice cream").is_empty());
+        assert!(forms("").is_empty());
+    }
+}
@@ -0,0 +1,3 @@
+mod lemmatizer;
+
+pub use lemmatizer::*;
@@ -1,19 +1,26 @@
 mod bloc;
 mod bot;
+mod callbacks;
 mod commands;
 mod cron;
 mod datamuse;
+mod etymology;
 mod format;
 mod inlines;
+mod lemma;
 mod networking;
+mod pronunciation;
 mod server;
 mod service;
+mod settings;
 mod stands4;
+mod text;
 mod urban;
 mod wordle;
 
 use crate::service::telegram::TelegramService;
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 
 #[derive(Deserialize)]
@@ -26,6 +33,35 @@ struct Config {
     stands4_user_id: String,
     #[serde(rename = "STANDS4_TOKEN")]
     stands4_token: String,
+    #[serde(rename = "PRONUNCIATION_BASE_URL", default)]
+    pronunciation_base_url: Option<String>,
+    #[serde(rename = "ETYMOLOGY_BASE_URL", default)]
+    etymology_base_url: Option<String>,
+    #[serde(rename = "SETTINGS_PATH", default)]
+    settings_path: Option<String>,
+    #[serde(rename = "CHOSEN_RESULTS_PATH", default)]
+    chosen_results_path: Option<String>,
+    #[serde(rename = "INLINE_DEBOUNCE_MS", default)]
+    inline_debounce_ms: Option<u64>,
+    #[serde(rename = "HTTP_CONNECT_TIMEOUT_MS", default)]
+    http_connect_timeout_ms: Option<u64>,
+    #[serde(rename = "HTTP_READ_TIMEOUT_MS", default)]
+    http_read_timeout_ms: Option<u64>,
+    #[serde(rename = "HTTP_MAX_ATTEMPTS", default)]
+    http_max_attempts: Option<u32>,
+    #[serde(rename = "HTTP_OVERRIDES", default)]
+    http_overrides: HashMap<String, HttpOverride>,
+}
+
+/// The `HTTP_*` settings of a single upstream API, taking precedence over the global ones.
+#[derive(Deserialize, Default)]
+struct HttpOverride {
+    #[serde(rename = "CONNECT_TIMEOUT_MS", default)]
+    connect_timeout_ms: Option<u64>,
+    #[serde(rename = "READ_TIMEOUT_MS", default)]
+    read_timeout_ms: Option<u64>,
+    #[serde(rename = "MAX_ATTEMPTS", default)]
+    max_attempts: Option<u32>,
 }
 
 /// Program entry point that initializes logging, loads configuration from `Secrets.toml`,
@@ -44,7 +80,8 @@ struct Config {
 ///
 /// # Returns
 ///
-/// `Ok(())` if the service binds successfully, `Err` if configuration loading, parsing, or binding fails.
+/// `Ok(())` if the service binds successfully, `Err` if configuration loading, parsing, loading the
+/// chats' settings, or binding fails.
 #[tokio::main]
 async fn main() -> Result<(), anyhow::Error> {
     env_logger::init();
@@ -52,6 +89,6 @@ async fn main() -> Result<(), anyhow::Error> {
     let config: Config = toml::from_str(&config_str)?;
     let ip = Ipv4Addr::new(127, 0, 0, 1);
     let addr = SocketAddr::new(IpAddr::V4(ip), 8080);
-    let service = TelegramService::new(config);
+    let service = TelegramService::new(config)?;
     service.bind(addr).await
 }
@@ -1,8 +1,11 @@
+use crate::networking::retry::{RetryPolicy, is_retryable};
 use log::log_enabled;
+use rustify::enums::RequestMethod;
 use serde::de::DeserializeOwned;
 
 pub struct ApiClient {
     pub client: rustify::Client,
+    pub retry: RetryPolicy,
 }
 
 impl ApiClient {
@@ -11,6 +14,9 @@ impl ApiClient {
     /// This sends the provided `request` using the client's HTTP runtime, parses the response body into the
     /// endpoint's `Response` type, and converts that parsed response into `Entity` via `From<Response>`.
     ///
+    /// GET requests failing with a 5xx response, a timeout or a connection error are attempted again
+    /// as the client's `RetryPolicy` allows, other requests and failures are attempted once.
+    ///
     /// # Returns
     ///
     /// `Entity` converted from the endpoint's parsed response.
@@ -20,7 +26,7 @@ impl ApiClient {
     /// ```
     /// // async context required
     /// # async fn example_usage() -> anyhow::Result<()> {
-    /// // let client = ApiClient { client: /* initialized rustify::Client */ };
+    /// // let client = ApiClient { client: /* initialized rustify::Client */, retry: RetryPolicy::default() };
     /// // let request = /* an Endpoint implementation */;
     /// // let entity = client.exec(request).await?;
     /// # Ok(())
@@ -36,9 +42,29 @@ impl ApiClient {
         Entity: From<Response>,
     {
         let url = request.url(self.client.base.as_str())?;
-        log::info!("REQUEST URL {:?}", url);
-
-        let response = request.exec(&self.client).await?;
+        let idempotent = matches!(request.method(), RequestMethod::GET);
+        let mut attempt = 1;
+        let response = loop {
+            log::info!("REQUEST URL {:?} (attempt {})", url, attempt);
+            match request.exec(&self.client).await {
+                Ok(response) => break response,
+                Err(err)
+                    if idempotent && attempt < self.retry.max_attempts && is_retryable(&err) =>
+                {
+                    let delay = self.retry.delay(attempt);
+                    log::warn!(
+                        "REQUEST URL {:?} failed (attempt {}), retrying in {:?}: {:?}",
+                        url,
+                        attempt,
+                        delay,
+                        err
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                Err(err) => return Err(err.into()),
+            }
+        };
         if log_enabled!(log::Level::Debug) {
             let str = String::from_utf8(response.raw());
             if let Ok(str) = str {
@@ -55,4 +81,107 @@ impl ApiClient {
 
         Ok(response.into())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::networking::fixtures::serve;
+    use crate::networking::timeouts::Timeouts;
+    use axum::http::StatusCode;
+    use axum::routing::get;
+    use rustify_derive::Endpoint;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[derive(Endpoint)]
+    #[endpoint(path = "/{self.status}", response = "serde_json::Value")]
+    struct FlakyRequest {
+        status: u16,
+    }
+
+    /// Serves `/<status>` failing with the status until its third request, returning the base URL and the request count.
+    async fn flaky_server() -> (String, Arc<AtomicUsize>) {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+        let app = axum::Router::new().route(
+            "/{status}",
+            get(
+                move |axum::extract::Path(status): axum::extract::Path<u16>| async move {
+                    match counter.fetch_add(1, Ordering::SeqCst) {
+                        0 | 1 => (StatusCode::from_u16(status).unwrap(), "{}"),
+                        _ => (StatusCode::OK, "{}"),
+                    }
+                },
+            ),
+        );
+        (serve(app).await, requests)
+    }
+
+    /// Serves `/<status>` too slowly to answer within 100ms on its first request only,
+    /// returning the base URL and the request count.
+    async fn slow_server() -> (String, Arc<AtomicUsize>) {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+        let app = axum::Router::new().route(
+            "/{status}",
+            get(move || async move {
+                if counter.fetch_add(1, Ordering::SeqCst) == 0 {
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                }
+                "{}"
+            }),
+        );
+        (serve(app).await, requests)
+    }
+
+    fn client(base_url: &str) -> ApiClient {
+        client_with(base_url, Default::default())
+    }
+
+    fn client_with(base_url: &str, http: reqwest::Client) -> ApiClient {
+        ApiClient {
+            client: rustify::Client::new(base_url, http),
+            retry: RetryPolicy {
+                max_attempts: 3,
+                base_delay: Duration::from_millis(1),
+                max_delay: Duration::from_millis(2),
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn server_errors_are_retried() {
+        let (base_url, requests) = flaky_server().await;
+        let response = client(&base_url)
+            .exec::<serde_json::Value, _, _>(FlakyRequest { status: 503 })
+            .await;
+        assert!(response.is_ok());
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn timeouts_are_retried() {
+        let (base_url, requests) = slow_server().await;
+        let timeouts = Timeouts {
+            connect: Duration::from_secs(1),
+            read: Duration::from_millis(100),
+        };
+        let response = client_with(&base_url, timeouts.client())
+            .exec::<serde_json::Value, _, _>(FlakyRequest { status: 200 })
+            .await;
+        assert!(response.is_ok());
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn client_errors_are_not_retried() {
+        let (base_url, requests) = flaky_server().await;
+        let response = client(&base_url)
+            .exec::<serde_json::Value, _, _>(FlakyRequest { status: 404 })
+            .await;
+        assert!(response.is_err());
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,14 @@
+/// Serves the fixture `app` on a random local port, returning the base URL to reach it.
+///
+/// # Examples
+///
+/// ```ignore
+/// let app = axum::Router::new().route("/entries/en/hello", get(|| async { HELLO }));
+/// let base_url = serve(app).await;
+/// ```
+pub async fn serve(app: axum::Router) -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, app).await });
+    format!("http://{}", addr)
+}
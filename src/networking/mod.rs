@@ -1 +1,5 @@
 pub mod api_client;
+#[cfg(test)]
+pub mod fixtures;
+pub mod retry;
+pub mod timeouts;
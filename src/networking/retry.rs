@@ -0,0 +1,94 @@
+use rustify::errors::ClientError;
+use std::time::Duration;
+
+/// How a failed request is retried.
+///
+/// Only idempotent GETs are retried, and only when the failure is likely to be transient:
+/// a server error, a timeout or a connection failure. The delay between attempts doubles
+/// after every failure, up to `max_delay`, and is jittered so that concurrent requests
+/// don't retry in lockstep.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// How many times a request is attempted in total, including the first attempt.
+    pub max_attempts: u32,
+    /// The delay after the first failed attempt, before jitter.
+    pub base_delay: Duration,
+    /// The longest delay between attempts, before jitter.
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Computes how long to wait after the given failed attempt, counting from 1.
+    ///
+    /// The backoff is `base_delay * 2^(attempt - 1)` capped at `max_delay`,
+    /// of which a random half is taken off.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let policy = RetryPolicy::default();
+    /// let delay = policy.delay(2);
+    /// assert!(delay >= policy.base_delay && delay <= policy.base_delay * 2);
+    /// ```
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        let backoff = self.base_delay.saturating_mul(factor).min(self.max_delay);
+        backoff / 2 + backoff.mul_f64(fastrand::f64()) / 2
+    }
+}
+
+impl Default for RetryPolicy {
+    /// Three attempts, waiting 200ms and then 400ms before jitter.
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(2),
+        }
+    }
+}
+
+/// Tells whether the request failed in a way that another attempt may not.
+///
+/// # Returns
+///
+/// `true` for 5xx responses, timeouts and connection failures, `false` for anything else, 4xx responses included.
+pub fn is_retryable(err: &ClientError) -> bool {
+    match err {
+        ClientError::ServerResponseError { code, .. } => *code >= 500,
+        ClientError::RequestError { source, .. } | ClientError::ResponseError { source } => source
+            .downcast_ref::<reqwest::Error>()
+            .is_some_and(|err| err.is_timeout() || err.is_connect()),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delays_grow_up_to_the_cap() {
+        let policy = RetryPolicy {
+            max_attempts: 10,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(300),
+        };
+        for (attempt, backoff) in [(1, 100), (2, 200), (3, 300), (8, 300)] {
+            let backoff = Duration::from_millis(backoff);
+            let delay = policy.delay(attempt);
+            assert!(delay >= backoff / 2 && delay <= backoff, "{:?}", delay);
+        }
+    }
+
+    #[test]
+    fn only_server_errors_are_retried() {
+        let response = |code| ClientError::ServerResponseError {
+            code,
+            content: None,
+        };
+        assert!(is_retryable(&response(503)));
+        assert!(!is_retryable(&response(404)));
+        assert!(!is_retryable(&response(429)));
+    }
+}
@@ -0,0 +1,56 @@
+use std::time::Duration;
+
+/// How the bot introduces itself to the upstream APIs, some of which, like Wikimedia's, reject anonymous requests.
+pub const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (https://t.me/WordsLookupBot)"
+);
+
+/// How long a client waits on the upstream before giving up on a request.
+#[derive(Debug, Clone)]
+pub struct Timeouts {
+    /// How long establishing the connection may take.
+    pub connect: Duration,
+    /// How long the upstream may stay silent while responding.
+    pub read: Duration,
+}
+
+impl Timeouts {
+    /// Builds an HTTP client enforcing the timeouts and sending the bot's [`USER_AGENT`].
+    ///
+    /// Falls back to a client without timeouts if the configured one can't be built,
+    /// which only happens when the TLS backend fails to initialize.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let timeouts = Timeouts {
+    ///     connect: Duration::from_secs(2),
+    ///     read: Duration::from_secs(5),
+    /// };
+    /// let client = PronunciationClient::new(timeouts.client(), "http://127.0.0.1:8081".to_string());
+    /// ```
+    pub fn client(&self) -> reqwest::Client {
+        reqwest::Client::builder()
+            .user_agent(USER_AGENT)
+            .connect_timeout(self.connect)
+            .read_timeout(self.read)
+            .build()
+            .unwrap_or_else(|err| {
+                log::error!("Failed to build an HTTP client with timeouts: {:?}", err);
+                reqwest::Client::new()
+            })
+    }
+}
+
+impl Default for Timeouts {
+    /// 5 seconds to connect and 10 seconds to respond.
+    fn default() -> Self {
+        Timeouts {
+            connect: Duration::from_secs(5),
+            read: Duration::from_secs(10),
+        }
+    }
+}
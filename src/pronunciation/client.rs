@@ -0,0 +1,152 @@
+use crate::networking::api_client::ApiClient;
+use crate::networking::retry::RetryPolicy;
+use crate::networking::timeouts::Timeouts;
+use crate::pronunciation::{EntryResponse, Pronunciation};
+use rustify::errors::ClientError;
+
+/// The public Free Dictionary API, used unless configured otherwise.
+pub const DEFAULT_BASE_URL: &str = "https://api.dictionaryapi.dev/api/v2";
+
+#[derive(Debug, Clone)]
+pub struct PronunciationClient {
+    client: reqwest::Client,
+    base_url: String,
+    retry: RetryPolicy,
+}
+
+impl PronunciationClient {
+    /// Create a client for a Free Dictionary API compatible pronunciation source.
+    ///
+    /// The `base_url` is the root the `/entries/en/{word}` paths are resolved against, which allows
+    /// pointing the client at a mirror or at a local fixture server.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let client = PronunciationClient::new(reqwest::Client::new(), "http://127.0.0.1:8081".to_string());
+    /// ```
+    pub fn new(client: reqwest::Client, base_url: String) -> Self {
+        PronunciationClient {
+            client,
+            base_url,
+            retry: RetryPolicy::default(),
+        }
+    }
+
+    /// Sets how the client's failed requests are retried, `RetryPolicy::default()` unless set.
+    ///
+    /// Pronunciations are looked up alongside the definitions, so a single attempt may suit them better.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let client = PronunciationClient::default().with_retry(RetryPolicy {
+    ///     max_attempts: 1,
+    ///     ..Default::default()
+    /// });
+    /// ```
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Creates an ApiClient targeted at the configured base URL, using the internal reqwest client.
+    fn client(&self) -> ApiClient {
+        ApiClient {
+            client: rustify::Client::new(&self.base_url, self.client.clone()),
+            retry: self.retry.clone(),
+        }
+    }
+
+    /// Execute the given Endpoint against the pronunciation source and extract the word's pronunciation.
+    ///
+    /// The source responds with 404 to unknown words, which yields `Ok(None)` rather than an error.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(Some(Pronunciation))` if a transcription or a recording is known, `Ok(None)` otherwise,
+    /// or an `Err` if the request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() -> anyhow::Result<()> {
+    /// let client = PronunciationClient::default();
+    /// let pronunciation = client.exec(PronunciationRequest::new("hello".to_string())).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn exec<Endpoint: rustify::Endpoint<Response = Vec<EntryResponse>>>(
+        &self,
+        request: Endpoint,
+    ) -> anyhow::Result<Option<Pronunciation>> {
+        self.client()
+            .exec::<Vec<EntryResponse>, _, _>(request)
+            .await
+            .map(|entries| Pronunciation::from_entries(&entries))
+            .or_else(|err| match err.downcast::<ClientError>()? {
+                ClientError::ServerResponseError { code: 404, .. } => Ok(None),
+                it => Err(it.into()),
+            })
+    }
+}
+
+impl Default for PronunciationClient {
+    /// Create a PronunciationClient targeting the public Free Dictionary API with the default `Timeouts`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let _: PronunciationClient = PronunciationClient::default();
+    /// ```
+    fn default() -> Self {
+        PronunciationClient::new(Timeouts::default().client(), DEFAULT_BASE_URL.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::networking::fixtures::serve;
+    use crate::pronunciation::requests::PronunciationRequest;
+    use axum::http::StatusCode;
+    use axum::routing::get;
+
+    const HELLO: &str = r#"[{"word":"hello","phonetic":"/həˈləʊ/","phonetics":[{"text":"/həˈləʊ/","audio":"https://example.com/hello.mp3"}]}]"#;
+    const NOT_FOUND: &str =
+        r#"{"title":"No Definitions Found","message":"Sorry pal.","resolution":""}"#;
+
+    /// Serves fixture responses on a random local port, returning the base URL to reach them.
+    async fn fixture_server() -> String {
+        let app = axum::Router::new()
+            .route("/entries/en/hello", get(|| async { HELLO }))
+            .fallback(|| async { (StatusCode::NOT_FOUND, NOT_FOUND) });
+        serve(app).await
+    }
+
+    #[tokio::test]
+    async fn known_word_has_pronunciation() {
+        let client = PronunciationClient::new(Default::default(), fixture_server().await);
+        let pronunciation = client
+            .exec(PronunciationRequest::new("hello".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(
+            pronunciation,
+            Some(Pronunciation {
+                ipa: Some("həˈləʊ".to_string()),
+                audio: Some("https://example.com/hello.mp3".to_string()),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn unknown_word_has_no_pronunciation() {
+        let client = PronunciationClient::new(Default::default(), fixture_server().await);
+        let pronunciation = client
+            .exec(PronunciationRequest::new("qwrtz".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(pronunciation, None);
+    }
+}
@@ -0,0 +1,6 @@
+pub mod client;
+pub mod requests;
+pub mod responses;
+
+pub use client::*;
+pub use responses::*;
@@ -0,0 +1,25 @@
+use crate::pronunciation::EntryResponse;
+use rustify_derive::Endpoint;
+
+#[derive(Endpoint)]
+#[endpoint(path = "/entries/en/{self.word}", response = "Vec<EntryResponse>")]
+pub struct PronunciationRequest {
+    #[endpoint(skip)]
+    pub(crate) word: String,
+}
+
+impl PronunciationRequest {
+    /// Creates a `PronunciationRequest` for the given word, URL-encoding it since it is a part of the path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let req = PronunciationRequest::new("ice cream".to_string());
+    /// assert_eq!(req.word, "ice%20cream");
+    /// ```
+    pub fn new(word: String) -> Self {
+        Self {
+            word: urlencoding::encode(&word).into_owned(),
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+pub struct EntryResponse {
+    #[serde(default)]
+    pub phonetic: Option<String>,
+    #[serde(default)]
+    pub phonetics: Vec<PhoneticResponse>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PhoneticResponse {
+    #[serde(default)]
+    pub text: Option<String>,
+    #[serde(default)]
+    pub audio: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pronunciation {
+    /// IPA transcription without the enclosing slashes, e.g. `həˈləʊ`.
+    pub ipa: Option<String>,
+    /// Absolute URL of a recording of the word.
+    pub audio: Option<String>,
+}
+
+impl Pronunciation {
+    /// Picks the first transcription and the first recording found across dictionary entries.
+    ///
+    /// Transcriptions are stripped of the enclosing slashes or brackets, while protocol-relative
+    /// recording URLs (`//host/path`) are turned into `https` ones.
+    ///
+    /// # Returns
+    ///
+    /// `Some(Pronunciation)`, or `None` if the entries carry neither a transcription nor a recording.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let pronunciation = Pronunciation::from_entries(&entries);
+    /// assert_eq!(pronunciation.unwrap().ipa.as_deref(), Some("həˈləʊ"));
+    /// ```
+    pub fn from_entries(entries: &[EntryResponse]) -> Option<Self> {
+        let ipa = entries
+            .iter()
+            .flat_map(|entry| {
+                let texts = entry
+                    .phonetics
+                    .iter()
+                    .map(|phonetic| phonetic.text.as_ref());
+                std::iter::once(entry.phonetic.as_ref()).chain(texts)
+            })
+            .flatten()
+            .map(|ipa| ipa.trim_matches(['/', '[', ']', ' ']))
+            .find(|ipa| !ipa.is_empty())
+            .map(str::to_string);
+        let audio = entries
+            .iter()
+            .flat_map(|entry| entry.phonetics.iter())
+            .filter_map(|phonetic| phonetic.audio.as_deref())
+            .find(|audio| !audio.is_empty())
+            .map(|audio| match audio.strip_prefix("//") {
+                Some(rest) => format!("https://{}", rest),
+                None => audio.to_string(),
+            });
+
+        match (&ipa, &audio) {
+            (None, None) => None,
+            _ => Some(Pronunciation { ipa, audio }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsing_pronunciation_works() {
+        let response = r#"[{"word":"hello","phonetic":"/həˈləʊ/","phonetics":[{"text":"/həˈləʊ/","audio":""},{"text":"/hɛˈləʊ/","audio":"//ssl.gstatic.com/dictionary/static/sounds/hello--_gb_1.mp3"}],"meanings":[]}]"#;
+        let entries = serde_json::from_str::<Vec<EntryResponse>>(response).unwrap();
+        let pronunciation = Pronunciation::from_entries(&entries).unwrap();
+        assert_eq!(pronunciation.ipa.as_deref(), Some("həˈləʊ"));
+        assert_eq!(
+            pronunciation.audio.as_deref(),
+            Some("https://ssl.gstatic.com/dictionary/static/sounds/hello--_gb_1.mp3")
+        );
+    }
+
+    #[test]
+    fn missing_phonetic_falls_back_to_phonetics() {
+        let response = r#"[{"word":"run","phonetics":[{"audio":""},{"text":"/ɹʌn/"}]}]"#;
+        let entries = serde_json::from_str::<Vec<EntryResponse>>(response).unwrap();
+        let pronunciation = Pronunciation::from_entries(&entries).unwrap();
+        assert_eq!(pronunciation.ipa.as_deref(), Some("ɹʌn"));
+        assert_eq!(pronunciation.audio, None);
+    }
+
+    #[test]
+    fn empty_entries_have_no_pronunciation() {
+        let response = r#"[{"word":"xyz","phonetics":[]}]"#;
+        let entries = serde_json::from_str::<Vec<EntryResponse>>(response).unwrap();
+        assert_eq!(Pronunciation::from_entries(&entries), None);
+    }
+}
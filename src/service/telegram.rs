@@ -1,11 +1,25 @@
-use crate::Config;
+use crate::{Config, HttpOverride};
 use crate::bot::runner::BotRunner;
 use crate::cron::runner::CronRunner;
+use crate::datamuse::client::DatamuseClient;
+use crate::etymology::EtymologyClient;
+use crate::inlines::{ChosenResultsLog, InlineQueryDebouncer};
+use crate::networking::retry::RetryPolicy;
+use crate::networking::timeouts::Timeouts;
+use crate::pronunciation::PronunciationClient;
 use crate::server::runner::ServerRunner;
+use crate::settings::SettingsStore;
 use crate::stands4::client::Stands4Client;
+use crate::urban::UrbanDictionaryClient;
 use crate::wordle::WordleClient;
 use crate::wordle::cache::WordleCache;
 use std::net::SocketAddr;
+use std::time::Duration;
+
+/// Where the chats' settings are kept unless configured otherwise.
+const DEFAULT_SETTINGS_PATH: &str = "settings.json";
+/// Where the chosen inline results are recorded unless configured otherwise.
+const DEFAULT_CHOSEN_RESULTS_PATH: &str = "chosen_results.jsonl";
 
 #[derive(Clone)]
 pub struct TelegramService {
@@ -13,12 +27,71 @@ pub struct TelegramService {
     pub(crate) token: String,
     pub(crate) stands4_client: Stands4Client,
     pub(crate) wordle_cache: WordleCache,
+    pub(crate) pronunciation_client: PronunciationClient,
+    pub(crate) etymology_client: EtymologyClient,
+    pub(crate) urban_client: UrbanDictionaryClient,
+    pub(crate) datamuse_client: DatamuseClient,
+    pub(crate) settings: SettingsStore,
+    pub(crate) chosen_results: ChosenResultsLog,
+    pub(crate) debouncer: InlineQueryDebouncer,
+}
+
+/// Builds the HTTP client and the retry policy of the upstream API called `name`.
+///
+/// The API's entry in `config.http_overrides`, e.g. `[HTTP_OVERRIDES.etymology]`, takes precedence
+/// over the global `HTTP_*` keys, which in turn take precedence over the `Timeouts` and `RetryPolicy` defaults.
+///
+/// # Examples
+///
+/// ```ignore
+/// let (http, retry) = upstream(&config, "stands4");
+/// let client = Stands4Client::new(http, user_id, token).with_retry(retry);
+/// ```
+fn upstream(config: &Config, name: &str) -> (reqwest::Client, RetryPolicy) {
+    let default = HttpOverride::default();
+    let own = config.http_overrides.get(name).unwrap_or(&default);
+    let millis = |own: Option<u64>, global: Option<u64>, fallback: Duration| {
+        own.or(global).map_or(fallback, Duration::from_millis)
+    };
+    let timeouts = Timeouts::default();
+    let timeouts = Timeouts {
+        connect: millis(
+            own.connect_timeout_ms,
+            config.http_connect_timeout_ms,
+            timeouts.connect,
+        ),
+        read: millis(
+            own.read_timeout_ms,
+            config.http_read_timeout_ms,
+            timeouts.read,
+        ),
+    };
+    let retry = RetryPolicy::default();
+    let retry = RetryPolicy {
+        max_attempts: own
+            .max_attempts
+            .or(config.http_max_attempts)
+            .unwrap_or(retry.max_attempts),
+        ..retry
+    };
+    (timeouts.client(), retry)
 }
 
 impl TelegramService {
     /// Creates a TelegramService configured from the provided `Config`.
     ///
     /// The constructor initializes the internal clients and cache and stores the admin chat ID and bot token from `config`.
+    /// The pronunciation client targets `config.pronunciation_base_url` when one is set, the public Free Dictionary API otherwise.
+    /// Likewise, the etymology client targets `config.etymology_base_url` or the English Wiktionary.
+    /// The chats' settings are loaded from `config.settings_path`, `settings.json` by default.
+    /// The chosen inline results are recorded to `config.chosen_results_path`, `chosen_results.jsonl` by default.
+    /// Inline queries are held back for `config.inline_debounce_ms` milliseconds while the user is typing, a second by default.
+    /// Every upstream API client gets the `Timeouts` and `RetryPolicy` built from the `config.http_*` keys,
+    /// or from its own entry in `config.http_overrides`, see [`upstream`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the existing settings file can't be loaded or the chosen results file can't be opened.
     ///
     /// # Examples
     ///
@@ -34,18 +107,64 @@ impl TelegramService {
     ///     ..Default::default()
     /// };
     ///
-    /// let svc = TelegramService::new(config);
+    /// let svc = TelegramService::new(config)?;
     /// // svc is ready to be bound or run
     /// ```
-    pub fn new(config: Config) -> Self {
-        let stands4_client = Stands4Client::new(config.stands4_user_id, config.stands4_token);
-        let wordle_cache = WordleCache::new(WordleClient::default(), stands4_client.clone());
-        TelegramService {
+    pub fn new(config: Config) -> anyhow::Result<Self> {
+        let (stands4_http, stands4_retry) = upstream(&config, "stands4");
+        let (wordle_http, wordle_retry) = upstream(&config, "wordle");
+        let (pronunciation_http, pronunciation_retry) = upstream(&config, "pronunciation");
+        let (etymology_http, etymology_retry) = upstream(&config, "etymology");
+        let (urban_http, urban_retry) = upstream(&config, "urban");
+        let (datamuse_http, datamuse_retry) = upstream(&config, "datamuse");
+        let stands4_client =
+            Stands4Client::new(stands4_http, config.stands4_user_id, config.stands4_token)
+                .with_retry(stands4_retry);
+        let wordle_client = WordleClient::new(wordle_http).with_retry(wordle_retry);
+        let wordle_cache = WordleCache::new(wordle_client, stands4_client.clone());
+        let pronunciation_client = PronunciationClient::new(
+            pronunciation_http,
+            config
+                .pronunciation_base_url
+                .unwrap_or_else(|| crate::pronunciation::DEFAULT_BASE_URL.to_string()),
+        )
+        .with_retry(pronunciation_retry);
+        let etymology_client = EtymologyClient::new(
+            etymology_http,
+            config
+                .etymology_base_url
+                .unwrap_or_else(|| crate::etymology::DEFAULT_BASE_URL.to_string()),
+        )
+        .with_retry(etymology_retry);
+        let urban_client = UrbanDictionaryClient::new(urban_http).with_retry(urban_retry);
+        let datamuse_client = DatamuseClient::new(datamuse_http).with_retry(datamuse_retry);
+        let settings = SettingsStore::load(
+            config
+                .settings_path
+                .unwrap_or_else(|| DEFAULT_SETTINGS_PATH.to_string()),
+        )?;
+        let chosen_results = ChosenResultsLog::open(
+            config
+                .chosen_results_path
+                .unwrap_or_else(|| DEFAULT_CHOSEN_RESULTS_PATH.to_string()),
+        )?;
+        let debouncer = match config.inline_debounce_ms {
+            Some(window) => InlineQueryDebouncer::new(Duration::from_millis(window)),
+            None => InlineQueryDebouncer::default(),
+        };
+        Ok(TelegramService {
             admin_chat: config.admin_chat,
             token: config.teloxide_token,
             stands4_client,
             wordle_cache,
-        }
+            pronunciation_client,
+            etymology_client,
+            urban_client,
+            datamuse_client,
+            settings,
+            chosen_results,
+            debouncer,
+        })
     }
 
     /// Runs the Telegram cron routine, the HTTP server bound to `addr`, and the Telegram bot dispatcher concurrently until they complete.
@@ -1,9 +1,9 @@
+use crate::bot::SentReplies;
 use crate::bot::runner::BotRunner;
+use crate::callbacks::callbacks_tree;
 use crate::commands::commands_tree;
-use crate::datamuse::client::DatamuseClient;
-use crate::inlines::{InlineQueryDebouncer, inlines_tree};
+use crate::inlines::{InlinePages, chosen_results_tree, inlines_tree};
 use crate::service::telegram::TelegramService;
-use crate::urban::UrbanDictionaryClient;
 use futures::FutureExt;
 use std::time::Duration;
 use teloxide::dispatching::{DefaultKey, Dispatcher};
@@ -18,7 +18,9 @@ impl TelegramService {
     /// Builds the dependency map used by the dispatcher.
     ///
     /// The map contains the service's shared dependencies: the STANDS4 client, the Wordle
-    /// cache, an inline-query debouncer, an UrbanDictionary client, and a Datamuse client.
+    /// cache, an inline-query debouncer, the kept pages of inline answers, an UrbanDictionary client, a Datamuse client, a pronunciation client,
+    /// an etymology client, the per-chat settings, the bot's replies to recent messages
+    /// and the log of chosen inline results.
     ///
     /// # Examples
     ///
@@ -32,16 +34,22 @@ impl TelegramService {
         deps![
             self.stands4_client.clone(),
             self.wordle_cache.clone(),
-            InlineQueryDebouncer::default(),
-            UrbanDictionaryClient::default(),
-            DatamuseClient::default()
+            self.debouncer.clone(),
+            InlinePages::default(),
+            self.urban_client.clone(),
+            self.datamuse_client.clone(),
+            self.pronunciation_client.clone(),
+            self.etymology_client.clone(),
+            self.settings.clone(),
+            SentReplies::default(),
+            self.chosen_results.clone()
         ]
     }
 
-    /// Builds and returns a Dispatcher for the given bot, wired with the bot's command and inline query trees.
+    /// Builds and returns a Dispatcher for the given bot, wired with the bot's command, inline query and callback trees.
     ///
     /// The returned dispatcher is configured with:
-    /// - a branch tree composed of inline and command handlers,
+    /// - a branch tree composed of inline, command and callback handlers and the recording of chosen inline results,
     /// - a default no-op handler for updates that are not of interest,
     /// - the service's dependency map, and
     /// - Ctrl+C shutdown handling.
@@ -60,7 +68,11 @@ impl TelegramService {
         // Other update types are of no interest to use since this REPL is only for
         // messages. See <https://github.com/teloxide/teloxide/issues/557>.
         let ignore_update = |_upd| Box::pin(async {});
-        let tree = entry().branch(inlines_tree()).branch(commands_tree());
+        let tree = entry()
+            .branch(inlines_tree())
+            .branch(commands_tree())
+            .branch(callbacks_tree())
+            .branch(chosen_results_tree());
 
         Dispatcher::builder(bot.clone(), tree)
             .default_handler(ignore_update)
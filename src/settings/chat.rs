@@ -0,0 +1,201 @@
+use serde::{Deserialize, Serialize};
+
+/// The choices of how many entries a lookup shows.
+const MAX_RESULTS_CHOICES: [usize; 3] = [3, 5, 10];
+
+/// Where plain text messages, i.e. the ones that aren't commands, are looked up.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LookupSource {
+    #[default]
+    Dictionary,
+    Thesaurus,
+    Urban,
+    All,
+}
+
+impl LookupSource {
+    /// Human-readable name of the source.
+    pub fn name(&self) -> &'static str {
+        match self {
+            LookupSource::Dictionary => "Dictionary",
+            LookupSource::Thesaurus => "Thesaurus",
+            LookupSource::Urban => "Urban Dictionary",
+            LookupSource::All => "All sources",
+        }
+    }
+
+    /// The source following this one, wrapping around after the last one.
+    fn next(&self) -> Self {
+        match self {
+            LookupSource::Dictionary => LookupSource::Thesaurus,
+            LookupSource::Thesaurus => LookupSource::Urban,
+            LookupSource::Urban => LookupSource::All,
+            LookupSource::All => LookupSource::Dictionary,
+        }
+    }
+}
+
+/// Preferences of a single chat.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChatSettings {
+    /// Whether plain single-word messages are looked up in a group chat,
+    /// private chats always look them up.
+    pub auto_lookup: bool,
+    /// How many entries a lookup shows, the rest are left to the website.
+    pub max_results: usize,
+    /// Whether the answer in Wordle responses is hidden behind a spoiler.
+    pub wordle_spoiler: bool,
+    /// Whether Urban Dictionary, with its not always family-friendly content, may be looked up.
+    pub urban_allowed: bool,
+    /// Where plain text messages are looked up.
+    pub default_source: LookupSource,
+    /// Whether the responses leave out the examples.
+    pub compact: bool,
+}
+
+impl Default for ChatSettings {
+    /// Settings of a chat that has never changed them, matching how the bot behaves without any settings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let settings = ChatSettings::default();
+    /// assert_eq!(settings.max_results, 5);
+    /// assert!(settings.urban_allowed);
+    /// ```
+    fn default() -> Self {
+        ChatSettings {
+            auto_lookup: false,
+            max_results: 5,
+            wordle_spoiler: false,
+            urban_allowed: true,
+            default_source: LookupSource::Dictionary,
+            compact: false,
+        }
+    }
+}
+
+/// A single preference that can be changed from the `/settings` menu.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Setting {
+    AutoLookup,
+    MaxResults,
+    WordleSpoiler,
+    UrbanAllowed,
+    DefaultSource,
+    Compact,
+}
+
+impl Setting {
+    pub const ALL: [Setting; 6] = [
+        Setting::MaxResults,
+        Setting::DefaultSource,
+        Setting::Compact,
+        Setting::UrbanAllowed,
+        Setting::WordleSpoiler,
+        Setting::AutoLookup,
+    ];
+
+    /// Short tag identifying the setting in callback data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert_eq!(Setting::from_tag(Setting::Compact.tag()), Some(Setting::Compact));
+    /// ```
+    pub fn tag(&self) -> &'static str {
+        match self {
+            Setting::AutoLookup => "auto",
+            Setting::MaxResults => "max",
+            Setting::WordleSpoiler => "spoiler",
+            Setting::UrbanAllowed => "urban",
+            Setting::DefaultSource => "source",
+            Setting::Compact => "compact",
+        }
+    }
+
+    /// Recognizes a setting by its [`Setting::tag`].
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        Setting::ALL
+            .into_iter()
+            .find(|setting| setting.tag() == tag)
+    }
+}
+
+impl ChatSettings {
+    /// Describes the current value of the setting, e.g. `"Max results: 5"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let settings = ChatSettings::default();
+    /// assert_eq!(settings.describe(Setting::Compact), "Output: detailed");
+    /// ```
+    pub fn describe(&self, setting: Setting) -> String {
+        let on_off = |value: bool| if value { "on" } else { "off" };
+        match setting {
+            Setting::AutoLookup => format!("Auto lookup in groups: {}", on_off(self.auto_lookup)),
+            Setting::MaxResults => format!("Max results: {}", self.max_results),
+            Setting::WordleSpoiler => format!("Wordle spoiler: {}", on_off(self.wordle_spoiler)),
+            Setting::UrbanAllowed => format!("Urban Dictionary: {}", on_off(self.urban_allowed)),
+            Setting::DefaultSource => format!("Plain text: {}", self.default_source.name()),
+            Setting::Compact => match self.compact {
+                true => "Output: compact".to_string(),
+                false => "Output: detailed".to_string(),
+            },
+        }
+    }
+
+    /// Moves the setting on to its next value: flags are flipped, while the number of results
+    /// and the source go through their choices in turn.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut settings = ChatSettings::default();
+    /// settings.cycle(Setting::MaxResults);
+    /// assert_eq!(settings.max_results, 10);
+    /// ```
+    pub fn cycle(&mut self, setting: Setting) {
+        match setting {
+            Setting::AutoLookup => self.auto_lookup = !self.auto_lookup,
+            Setting::MaxResults => {
+                self.max_results = MAX_RESULTS_CHOICES
+                    .into_iter()
+                    .find(|choice| *choice > self.max_results)
+                    .unwrap_or(MAX_RESULTS_CHOICES[0])
+            }
+            Setting::WordleSpoiler => self.wordle_spoiler = !self.wordle_spoiler,
+            Setting::UrbanAllowed => self.urban_allowed = !self.urban_allowed,
+            Setting::DefaultSource => self.default_source = self.default_source.next(),
+            Setting::Compact => self.compact = !self.compact,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cycling_wraps_around() {
+        let mut settings = ChatSettings::default();
+        settings.cycle(Setting::MaxResults);
+        settings.cycle(Setting::MaxResults);
+        assert_eq!(settings.max_results, 3);
+        for _ in 0..4 {
+            settings.cycle(Setting::DefaultSource);
+        }
+        assert_eq!(settings.default_source, LookupSource::Dictionary);
+    }
+
+    #[test]
+    fn missing_fields_fall_back_to_defaults() {
+        let settings: ChatSettings = serde_json::from_str(r#"{"max_results": 10}"#).unwrap();
+        assert_eq!(settings.max_results, 10);
+        assert!(settings.urban_allowed);
+        assert_eq!(settings.default_source, LookupSource::Dictionary);
+    }
+}
@@ -0,0 +1,5 @@
+mod chat;
+mod store;
+
+pub use chat::*;
+pub use store::*;
@@ -0,0 +1,184 @@
+use crate::settings::ChatSettings;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use teloxide::types::ChatId;
+
+/// Preferences of every chat the bot is in, shared between the handlers through the dependency map.
+///
+/// When backed by a file, the preferences are kept there as a JSON object from chat IDs to settings,
+/// rewritten on every change off the async runtime's threads.
+#[derive(Debug, Clone, Default)]
+pub struct SettingsStore {
+    chats: Arc<Mutex<HashMap<ChatId, ChatSettings>>>,
+    path: Option<PathBuf>,
+    /// How many changes have been made, numbering the snapshots of the settings to save.
+    changes: Arc<AtomicU64>,
+    /// The number of the snapshot last saved, locked for the whole write so that writes don't overlap.
+    saved: Arc<Mutex<u64>>,
+}
+
+impl SettingsStore {
+    /// Opens the store backed by the JSON file at `path`, which is created on the first change if missing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the existing file can't be read or doesn't hold valid settings,
+    /// rather than overwriting it with an empty store later on.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// let store = SettingsStore::load("settings.json")?;
+    /// ```
+    pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let chats = match std::fs::read_to_string(&path) {
+            Ok(json) => serde_json::from_str::<HashMap<i64, ChatSettings>>(&json)?
+                .into_iter()
+                .map(|(chat, settings)| (ChatId(chat), settings))
+                .collect(),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => HashMap::new(),
+            Err(err) => return Err(err.into()),
+        };
+        log::info!("Loaded settings of {} chats", chats.len());
+        Ok(SettingsStore {
+            chats: Arc::new(Mutex::new(chats)),
+            path: Some(path),
+            ..Default::default()
+        })
+    }
+
+    /// Returns the settings of the chat, or the default ones if the chat has never changed them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let store = SettingsStore::default();
+    /// assert!(!store.get(ChatId(42)).auto_lookup);
+    /// ```
+    pub fn get(&self, chat: ChatId) -> ChatSettings {
+        self.chats
+            .lock()
+            .unwrap()
+            .get(&chat)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Changes the settings of the chat in place, saves them and returns the updated ones.
+    ///
+    /// The settings are only locked while being changed and copied, the file is written on a blocking thread.
+    /// A failure to save is logged, the change is kept in memory regardless.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let store = SettingsStore::default();
+    /// let settings = store.update(ChatId(42), |settings| settings.auto_lookup = true).await;
+    /// assert!(settings.auto_lookup);
+    /// ```
+    pub async fn update<F>(&self, chat: ChatId, change: F) -> ChatSettings
+    where
+        F: FnOnce(&mut ChatSettings),
+    {
+        let (settings, snapshot) = {
+            let mut chats = self.chats.lock().unwrap();
+            let settings = chats.entry(chat).or_default();
+            change(settings);
+            let settings = settings.clone();
+            let change = self.changes.fetch_add(1, Ordering::SeqCst) + 1;
+            let snapshot = self.path.is_some().then(|| {
+                chats
+                    .iter()
+                    .map(|(chat, settings)| (chat.0, settings.clone()))
+                    .collect::<HashMap<_, _>>()
+            });
+            (settings, snapshot.map(|chats| (change, chats)))
+        };
+        if let Some((change, chats)) = snapshot {
+            let store = self.clone();
+            let saved = tokio::task::spawn_blocking(move || store.save(change, chats))
+                .await
+                .map_err(anyhow::Error::from)
+                .and_then(|saved| saved);
+            if let Err(err) = saved {
+                log::error!("Failed to save settings: {:?}", err);
+            }
+        }
+        settings
+    }
+
+    /// Writes the snapshot of all the settings to the backing file, if any, replacing it only once fully written.
+    ///
+    /// A snapshot older than the one already saved is skipped, so that a slow write doesn't undo a newer one.
+    fn save(&self, change: u64, chats: HashMap<i64, ChatSettings>) -> anyhow::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let mut saved = self.saved.lock().unwrap();
+        if *saved >= change {
+            return Ok(());
+        }
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, serde_json::to_string_pretty(&chats)?)?;
+        std::fs::rename(&tmp, path)?;
+        *saved = change;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn update_is_kept_per_chat() {
+        let store = SettingsStore::default();
+        store
+            .update(ChatId(1), |settings| settings.auto_lookup = true)
+            .await;
+        assert!(store.get(ChatId(1)).auto_lookup);
+        assert!(!store.get(ChatId(2)).auto_lookup);
+    }
+
+    #[tokio::test]
+    async fn settings_survive_reload() {
+        let path = std::env::temp_dir().join(format!("settings-{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let store = SettingsStore::load(&path).unwrap();
+        store
+            .update(ChatId(-100), |settings| settings.max_results = 10)
+            .await;
+
+        let store = SettingsStore::load(&path).unwrap();
+        assert_eq!(store.get(ChatId(-100)).max_results, 10);
+        assert_eq!(store.get(ChatId(1)), ChatSettings::default());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn older_snapshot_does_not_overwrite_newer() {
+        let path = std::env::temp_dir().join(format!("settings-order-{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let store = SettingsStore::load(&path).unwrap();
+        let snapshot = |max_results| {
+            HashMap::from([(
+                1,
+                ChatSettings {
+                    max_results,
+                    ..Default::default()
+                },
+            )])
+        };
+        store.save(2, snapshot(20)).unwrap();
+        store.save(1, snapshot(10)).unwrap();
+
+        let store = SettingsStore::load(&path).unwrap();
+        assert_eq!(store.get(ChatId(1)).max_results, 20);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -1,4 +1,5 @@
 use crate::networking::api_client::ApiClient;
+use crate::networking::retry::RetryPolicy;
 use crate::stands4::config::Stands4Config;
 use crate::stands4::responses::Results;
 use serde::de::DeserializeOwned;
@@ -8,31 +9,47 @@ use std::fmt::Debug;
 pub struct Stands4Client {
     client: reqwest::Client,
     config: Stands4Config,
+    retry: RetryPolicy,
 }
 
 impl Stands4Client {
-    /// Creates a new Stands4Client configured with the given user ID and token.
+    /// Creates a new Stands4Client sending its requests with the given reqwest client,
+    /// configured with the given user ID and token.
     ///
     /// # Examples
     ///
     /// ```
-    /// let client = Stands4Client::new("user_id".to_string(), "token".to_string());
+    /// let client = Stands4Client::new(Timeouts::default().client(), "user_id".to_string(), "token".to_string());
     /// // use `client` to execute requests against the Stands4 API
     /// ```
-    pub fn new(user_id: String, token: String) -> Self {
+    pub fn new(client: reqwest::Client, user_id: String, token: String) -> Self {
         Stands4Client {
-            client: Default::default(),
+            client,
             config: Stands4Config::new(user_id, token),
+            retry: RetryPolicy::default(),
         }
     }
 
+    /// Sets how the client's failed requests are retried, `RetryPolicy::default()` unless set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let client = Stands4Client::new(Timeouts::default().client(), "uid".into(), "token".into())
+    ///     .with_retry(RetryPolicy { max_attempts: 2, ..Default::default() });
+    /// ```
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
     /// Creates an ApiClient configured for the Stands4 v2 service using the client's internal HTTP client.
     ///
     ///
     /// # Examples
     ///
     /// ```
-    /// let s = Stands4Client::new("user".into(), "token".into());
+    /// let s = Stands4Client::new(Default::default(), "user".into(), "token".into());
     /// let api = s.client();
     /// // `api` is ready to execute endpoints against https://www.stands4.com/services/v2
     /// ```
@@ -42,6 +59,7 @@ impl Stands4Client {
                 "https://www.stands4.com/services/v2",
                 self.client.clone(),
             ),
+            retry: self.retry.clone(),
         }
     }
 
@@ -64,7 +82,7 @@ impl Stands4Client {
     /// use crate::stands4::client::Stands4Client;
     /// use crate::stands4::responses::WordResult;
     ///
-    /// let client = Stands4Client::new("uid".into(), "token".into());
+    /// let client = Stands4Client::new(Default::default(), "uid".into(), "token".into());
     /// let req = client.client().get("https://example.com/api");
     /// let items: Vec<WordResult> = client.exec(req).await?;
     /// # Ok(())
@@ -85,8 +103,9 @@ impl Stands4Client {
 
 #[cfg(test)]
 mod tests {
+    use crate::stands4::QuoteDefinition;
     use crate::stands4::client::Results;
-    use crate::stands4::responses::{PhraseResult, VecMixedType, WordResult};
+    use crate::stands4::responses::{PhraseResult, QuoteResult, VecMixedType, WordResult};
 
     #[test]
     fn parsing_words_works() {
@@ -134,4 +153,23 @@ mod tests {
         };
         assert_eq!(vec.len(), 1, "every item should be properly parsed");
     }
-}
\ No newline at end of file
+    #[test]
+    fn parsing_quotes_works() {
+        let response = "{\"result\":[{\"quote\":\"Courage is grace under pressure.\",\"author\":\"Ernest Hemingway\"},{\"quote\":\"Fortune favors the bold.\",\"author\":{}}]}";
+        let parsed = serde_json::from_slice::<Results<QuoteResult>>(response.as_bytes()).unwrap();
+        let quotes: anyhow::Result<Vec<QuoteDefinition>> = parsed.into();
+        assert_eq!(
+            quotes.unwrap(),
+            vec![
+                QuoteDefinition {
+                    quote: "Courage is grace under pressure.".to_string(),
+                    author: "Ernest Hemingway".to_string(),
+                },
+                QuoteDefinition {
+                    quote: "Fortune favors the bold.".to_string(),
+                    author: String::new(),
+                },
+            ]
+        );
+    }
+}
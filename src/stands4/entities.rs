@@ -19,6 +19,86 @@ impl ToEscaped for WordDefinition {
     }
 }
 
+impl WordDefinition {
+    /// Normalized part of speech of the definition, if Stands4 specified a known one.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// // Stands4 reports both "noun" and "Noun" depending on the source of a definition.
+    /// assert_eq!(def.pos(), Some(PartOfSpeech::Noun));
+    /// ```
+    pub fn pos(&self) -> Option<PartOfSpeech> {
+        PartOfSpeech::parse(&self.part_of_speech)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PartOfSpeech {
+    Noun,
+    Verb,
+    Adjective,
+    Adverb,
+    Pronoun,
+    Preposition,
+    Conjunction,
+    Interjection,
+}
+
+impl PartOfSpeech {
+    /// Parses a part of speech, ignoring casing and surrounding whitespace.
+    ///
+    /// Besides full names, common abbreviations are accepted, like `v` or `adj`,
+    /// including WordNet's `r` for adverbs and `s` for satellite adjectives.
+    ///
+    /// # Returns
+    ///
+    /// `Some(PartOfSpeech)` for a recognized value, `None` for an empty or unknown one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert_eq!(PartOfSpeech::parse("Noun"), Some(PartOfSpeech::Noun));
+    /// assert_eq!(PartOfSpeech::parse("v"), Some(PartOfSpeech::Verb));
+    /// assert_eq!(PartOfSpeech::parse(""), None);
+    /// ```
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "n" | "noun" => Some(PartOfSpeech::Noun),
+            "v" | "verb" => Some(PartOfSpeech::Verb),
+            "a" | "s" | "adj" | "adjective" | "adjective satellite" => {
+                Some(PartOfSpeech::Adjective)
+            }
+            "r" | "adv" | "adverb" => Some(PartOfSpeech::Adverb),
+            "pron" | "pronoun" => Some(PartOfSpeech::Pronoun),
+            "prep" | "preposition" => Some(PartOfSpeech::Preposition),
+            "conj" | "conjunction" => Some(PartOfSpeech::Conjunction),
+            "interj" | "interjection" => Some(PartOfSpeech::Interjection),
+            _ => None,
+        }
+    }
+
+    /// Lowercase full name of the part of speech, as shown to the user.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert_eq!(PartOfSpeech::Verb.name(), "verb");
+    /// ```
+    pub fn name(&self) -> &'static str {
+        match self {
+            PartOfSpeech::Noun => "noun",
+            PartOfSpeech::Verb => "verb",
+            PartOfSpeech::Adjective => "adjective",
+            PartOfSpeech::Adverb => "adverb",
+            PartOfSpeech::Pronoun => "pronoun",
+            PartOfSpeech::Preposition => "preposition",
+            PartOfSpeech::Conjunction => "conjunction",
+            PartOfSpeech::Interjection => "interjection",
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct PhraseDefinition {
     pub(crate) term: String,
@@ -51,6 +131,21 @@ impl ToEscaped for AbbreviationDefinition {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuoteDefinition {
+    pub(crate) quote: String,
+    pub(crate) author: String,
+}
+
+impl ToEscaped for QuoteDefinition {
+    fn to_escaped(&self) -> Self {
+        Self {
+            quote: self.quote.to_escaped(),
+            author: self.author.to_escaped(),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct SynAntDefinitions {
     pub(crate) term: String,
@@ -1,10 +1,12 @@
-use crate::stands4::entities::AbbreviationDefinition;
-use std::collections::HashMap;
+use crate::stands4::entities::{AbbreviationDefinition, PartOfSpeech, WordDefinition};
+use std::collections::{HashMap, HashSet};
 use std::ops::Not;
 
 pub trait SliceAbbreviationsExt {
     const UNFILLED: &'static str = "UNFILED";
+    const OTHER: &'static str = "Other";
     fn categorized(&self) -> Vec<(&str, Vec<&AbbreviationDefinition>)>;
+    fn categories(&self) -> Vec<(&str, Vec<&AbbreviationDefinition>)>;
 }
 
 impl SliceAbbreviationsExt for [AbbreviationDefinition] {
@@ -29,7 +31,270 @@ impl SliceAbbreviationsExt for [AbbreviationDefinition] {
             );
 
         let mut common = categorized.drain().collect::<Vec<_>>();
-        common.sort_by(|(_, v1), (_, v2)| v2.len().cmp(&v1.len()));
+        common.sort_by_key(|(_, v)| std::cmp::Reverse(v.len()));
         common
     }
+
+    /// Groups every abbreviation by its category, the unfiled ones included under `OTHER`.
+    ///
+    /// Categories are sorted by the number of definitions, most first, ties broken alphabetically,
+    /// so the order is the same from one lookup to another. `OTHER` always goes last.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let categories = definitions.categories();
+    /// // [("Business", [..3]), ("Computing", [..3]), ("Other", [..5])]
+    /// ```
+    fn categories(&self) -> Vec<(&str, Vec<&AbbreviationDefinition>)> {
+        let mut categories: Vec<(&str, Vec<&AbbreviationDefinition>)> = vec![];
+        for def in self {
+            let category = match def.category.as_str() {
+                "" | Self::UNFILLED => Self::OTHER,
+                category => category,
+            };
+            match categories.iter_mut().find(|(name, _)| *name == category) {
+                Some((_, defs)) => defs.push(def),
+                None => categories.push((category, vec![def])),
+            }
+        }
+        categories.sort_by(|(a, a_defs), (b, b_defs)| {
+            (*a == Self::OTHER)
+                .cmp(&(*b == Self::OTHER))
+                .then(b_defs.len().cmp(&a_defs.len()))
+                .then(a.cmp(b))
+        });
+        categories
+    }
+}
+
+/// Definitions longer than this are considered encyclopedia blurbs rather than dictionary senses.
+const BLURB_LEN: usize = 300;
+/// Share of the shorter definition's words found in the longer one, starting from which they are near-duplicates.
+const DUPLICATE_OVERLAP: f64 = 0.8;
+/// Words carrying no meaning on their own, ignored when comparing definitions.
+const STOP_WORDS: [&str; 16] = [
+    "a", "an", "the", "to", "of", "or", "and", "in", "on", "for", "with", "as", "by", "is", "it",
+    "that",
+];
+
+pub trait SliceWordsExt {
+    fn organized(&self) -> Vec<WordDefinition>;
+}
+
+impl SliceWordsExt for [WordDefinition] {
+    /// Tidies up word definitions merged by Stands4 from several dictionaries.
+    ///
+    /// Near-duplicate senses of the same part of speech are collapsed into the first of them, which inherits
+    /// an example from its duplicates if it has none. The remaining definitions are grouped by normalized part
+    /// of speech, the groups following the order in which Stands4 listed them, with the definitions of an
+    /// unknown part of speech last. Within a group, short dictionary senses go before long encyclopedia blurbs,
+    /// otherwise the order is preserved. Parts of speech are renamed to their normalized lowercase names.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let organized = definitions.organized();
+    /// // "sweeten with sugar" (verb) is collapsed into "To add sugar to; to sweeten with sugar." (Verb),
+    /// // and all nouns go before all verbs.
+    /// ```
+    fn organized(&self) -> Vec<WordDefinition> {
+        let mut kept: Vec<(WordDefinition, HashSet<String>)> = vec![];
+        for def in self {
+            let pos = def.pos();
+            let words = significant_words(&def.definition);
+            let duplicate = kept.iter_mut().find(|(other, other_words)| {
+                other.pos() == pos && are_near_duplicates(&words, other_words)
+            });
+            match duplicate {
+                Some((other, _)) if other.example.is_empty() => {
+                    other.example = def.example.clone();
+                }
+                Some(_) => {}
+                None => kept.push((def.clone(), words)),
+            }
+        }
+
+        let mut groups: Vec<Option<PartOfSpeech>> = vec![];
+        for (def, _) in &kept {
+            if let Some(pos) = def.pos()
+                && !groups.contains(&Some(pos))
+            {
+                groups.push(Some(pos));
+            }
+        }
+        groups.push(None);
+
+        let mut organized = kept
+            .into_iter()
+            .map(|(mut def, _)| {
+                if let Some(pos) = def.pos() {
+                    def.part_of_speech = pos.name().to_string();
+                }
+                def
+            })
+            .collect::<Vec<_>>();
+        organized.sort_by_key(|def| {
+            let group = groups.iter().position(|pos| *pos == def.pos());
+            (group, def.definition.len() > BLURB_LEN)
+        });
+        organized
+    }
+}
+
+/// Lowercase words of a definition, except for the stop words.
+fn significant_words(definition: &str) -> HashSet<String> {
+    definition
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .filter(|word| !STOP_WORDS.contains(&word.as_str()))
+        .collect()
+}
+
+/// Checks whether most of the words of the shorter definition are found in the longer one.
+///
+/// Definitions of a single significant word are only duplicates when they are equal,
+/// so that e.g. "A kiss." isn't swallowed by any definition mentioning a kiss.
+fn are_near_duplicates(a: &HashSet<String>, b: &HashSet<String>) -> bool {
+    let shorter = a.len().min(b.len());
+    if shorter < 2 {
+        return a == b;
+    }
+    let common = a.intersection(b).count();
+    common as f64 / shorter as f64 >= DUPLICATE_OVERLAP
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn def(definition: &str, example: &str, part_of_speech: &str) -> WordDefinition {
+        WordDefinition {
+            term: "sugar".to_string(),
+            definition: definition.to_string(),
+            example: example.to_string(),
+            part_of_speech: part_of_speech.to_string(),
+        }
+    }
+
+    fn definitions(defs: &[WordDefinition]) -> Vec<&str> {
+        defs.iter().map(|def| def.definition.as_str()).collect()
+    }
+
+    fn abbr(definition: &str, category: &str) -> AbbreviationDefinition {
+        AbbreviationDefinition {
+            definition: definition.to_string(),
+            category: category.to_string(),
+        }
+    }
+
+    #[test]
+    fn categories_keep_unfiled_as_other_last() {
+        let defs = [
+            abbr("As Soon As Possible", "UNFILED"),
+            abbr("Alternative Sentencing", "Governmental"),
+            abbr("Average Selling Price", "Business"),
+            abbr("Always Say Please", "UNFILED"),
+            abbr("Application Service Provider", "Computing"),
+            abbr("Active Server Pages", "Computing"),
+        ];
+        let categories = defs
+            .categories()
+            .into_iter()
+            .map(|(category, defs)| (category, defs.len()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            categories,
+            vec![
+                ("Computing", 2),
+                ("Business", 1),
+                ("Governmental", 1),
+                ("Other", 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn near_duplicates_are_collapsed() {
+        let defs = [
+            def("sweeten with sugar", "\"sugar your tea\"", "verb"),
+            def("To add sugar to; to sweeten with sugar.", "", "Verb"),
+            def("To make (something unpleasant) seem less so.", "", "Verb"),
+        ];
+        let organized = defs.organized();
+        assert_eq!(
+            definitions(&organized),
+            vec![
+                "sweeten with sugar",
+                "To make (something unpleasant) seem less so."
+            ]
+        );
+    }
+
+    #[test]
+    fn duplicate_lends_its_example() {
+        let defs = [
+            def("To add sugar to; to sweeten with sugar.", "", "Verb"),
+            def("sweeten with sugar", "\"sugar your tea\"", "verb"),
+        ];
+        let organized = defs.organized();
+        assert_eq!(organized.len(), 1);
+        assert_eq!(organized[0].example, "\"sugar your tea\"");
+    }
+
+    #[test]
+    fn short_single_word_senses_are_kept() {
+        let defs = [
+            def("A kiss.", "", "Noun"),
+            def("A term of endearment, like a kiss.", "", "Noun"),
+        ];
+        assert_eq!(defs.organized().len(), 2);
+    }
+
+    #[test]
+    fn definitions_are_grouped_by_normalized_pos() {
+        let blurb =
+            "Sugar is the generic name for sweet-tasting, soluble carbohydrates. ".repeat(5);
+        let defs = [
+            def(&blurb, "", ""),
+            def("a white crystalline carbohydrate", "", "noun"),
+            def("sweeten with sugar", "", "verb"),
+            def("A term of endearment.", "", "Noun"),
+            def("Used in place of shit!", "", "Interjection"),
+            def("Diabetes.", "", "Noun"),
+        ];
+        let organized = defs.organized();
+        assert_eq!(
+            definitions(&organized),
+            vec![
+                "a white crystalline carbohydrate",
+                "A term of endearment.",
+                "Diabetes.",
+                "sweeten with sugar",
+                "Used in place of shit!",
+                blurb.as_str(),
+            ]
+        );
+        let pos = organized
+            .iter()
+            .map(|def| def.part_of_speech.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            pos,
+            vec!["noun", "noun", "noun", "verb", "interjection", ""]
+        );
+    }
+
+    #[test]
+    fn short_senses_precede_blurbs() {
+        let blurb =
+            "Sugar is the generic name for sweet-tasting, soluble carbohydrates. ".repeat(5);
+        let defs = [def(&blurb, "", ""), def("Sugar, a band.", "", "")];
+        let organized = defs.organized();
+        assert_eq!(
+            definitions(&organized),
+            vec!["Sugar, a band.", blurb.as_str()]
+        );
+    }
 }
@@ -1,9 +1,11 @@
 mod search_abbreviations;
 mod search_phrase;
+mod search_quotes;
 mod search_syno;
 mod search_word;
 
 pub use search_abbreviations::SearchAbbrsRequest;
 pub use search_phrase::SearchPhraseRequest;
+pub use search_quotes::SearchQuotesRequest;
 pub use search_syno::SearchSynoRequest;
 pub use search_word::SearchWordRequest;
@@ -0,0 +1,45 @@
+use crate::stands4::responses::QuoteResult;
+use crate::stands4::responses::Results;
+use rustify_derive::Endpoint;
+use serde::Serialize;
+
+#[derive(Endpoint, Serialize)]
+#[endpoint(path = "/quotes.php", response = "Results<QuoteResult>")]
+pub struct SearchQuotesRequest {
+    #[endpoint(query)]
+    pub query: String,
+    #[endpoint(query)]
+    pub searchtype: String, // SEARCH for quotes containing the query, AUTHOR for quotes by it
+}
+
+impl SearchQuotesRequest {
+    /// Creates a request for the quotes that contain the given keyword.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let req = SearchQuotesRequest::by_keyword("courage".to_string());
+    /// assert_eq!(req.searchtype, "SEARCH");
+    /// ```
+    pub fn by_keyword(query: String) -> Self {
+        SearchQuotesRequest {
+            query,
+            searchtype: "SEARCH".to_string(),
+        }
+    }
+
+    /// Creates a request for the quotes said by the given author.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let req = SearchQuotesRequest::by_author("mark twain".to_string());
+    /// assert_eq!(req.searchtype, "AUTHOR");
+    /// ```
+    pub fn by_author(query: String) -> Self {
+        SearchQuotesRequest {
+            query,
+            searchtype: "AUTHOR".to_string(),
+        }
+    }
+}
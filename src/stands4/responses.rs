@@ -1,4 +1,6 @@
-use crate::stands4::{AbbreviationDefinition, PhraseDefinition, SynAntDefinitions, WordDefinition};
+use crate::stands4::{
+    AbbreviationDefinition, PhraseDefinition, QuoteDefinition, SynAntDefinitions, WordDefinition,
+};
 use serde::Deserialize;
 
 #[derive(Deserialize, Debug)]
@@ -196,6 +198,33 @@ impl From<SynAntResult> for SynAntDefinitions {
     }
 }
 
+#[derive(Deserialize, Debug)]
+pub struct QuoteResult {
+    quote: StringMixedType,
+    author: StringMixedType,
+}
+
+impl From<QuoteResult> for QuoteDefinition {
+    /// Converts a `QuoteResult` into a `QuoteDefinition`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let result = QuoteResult {
+    ///     quote: StringMixedType::String("Courage is grace under pressure.".into()),
+    ///     author: StringMixedType::String("Ernest Hemingway".into()),
+    /// };
+    /// let def: QuoteDefinition = result.into();
+    /// assert_eq!(def.author, "Ernest Hemingway");
+    /// ```
+    fn from(value: QuoteResult) -> Self {
+        QuoteDefinition {
+            quote: value.quote.into(),
+            author: value.author.into(),
+        }
+    }
+}
+
 #[derive(Deserialize, Debug)]
 // note, this causes deserialization to try the variants top-to-bottom
 #[serde(untagged)]
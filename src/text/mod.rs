@@ -0,0 +1,3 @@
+mod normalize;
+
+pub use normalize::*;
@@ -0,0 +1,74 @@
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+
+/// The characters a word can be made of, ready to be put into a regex character class:
+/// letters of any script with their combining marks, apostrophes and hyphens, e.g. `o'clock` or `well-being`.
+pub const WORD_CHARS: &str = r"\p{L}\p{M}'\-";
+
+/// Typographic apostrophes that keyboards substitute for `'`, e.g. in `o’clock`.
+const APOSTROPHES: [char; 4] = ['\u{2018}', '\u{2019}', '\u{02BC}', '\u{FF07}'];
+/// Typographic hyphens that keyboards substitute for `-`, e.g. in `well‐being`.
+const HYPHENS: [char; 3] = ['\u{2010}', '\u{2011}', '\u{FE63}'];
+
+/// Brings user input into the form every command and inline query is parsed in.
+///
+/// The text is lowercased and put into Unicode NFC, so that an accented letter typed as a letter
+/// followed by a combining mark is the same as the precomposed one. Typographic apostrophes
+/// and hyphens are replaced with their ASCII counterparts.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(normalize("Cafe\u{301}"), "café");
+/// assert_eq!(normalize("O’Clock"), "o'clock");
+/// ```
+pub fn normalize(text: &str) -> String {
+    text.to_lowercase()
+        .nfc()
+        .map(|char| match char {
+            char if APOSTROPHES.contains(&char) => '\'',
+            char if HYPHENS.contains(&char) => '-',
+            char => char,
+        })
+        .collect()
+}
+
+/// Strips the accents off the letters, for matching that doesn't tell `café` from `cafe`.
+///
+/// Only the marks that decompose off a letter are removed, letters like `ø` or `ß` are kept as they are.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(fold_accents("naïve résumé"), "naive resume");
+/// ```
+pub fn fold_accents(text: &str) -> String {
+    text.nfd()
+        .filter(|char| !is_combining_mark(*char))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_composes_accents() {
+        let decomposed = "cafe\u{301}";
+        assert_eq!(normalize(decomposed), "caf\u{e9}");
+        assert_eq!(normalize("CAFÉ"), "café");
+    }
+
+    #[test]
+    fn normalize_unifies_apostrophes_and_hyphens() {
+        assert_eq!(normalize("o\u{2019}clock"), "o'clock");
+        assert_eq!(normalize("well\u{2010}being"), "well-being");
+    }
+
+    #[test]
+    fn fold_accents_keeps_base_letters() {
+        assert_eq!(fold_accents("naïve"), "naive");
+        assert_eq!(fold_accents(&normalize("Re\u{301}sume\u{301}")), "resume");
+        assert_eq!(fold_accents("smørrebrød"), "smørrebrød");
+    }
+}
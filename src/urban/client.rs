@@ -1,4 +1,6 @@
 use crate::networking::api_client::ApiClient;
+use crate::networking::retry::RetryPolicy;
+use crate::networking::timeouts::Timeouts;
 use crate::urban::{UrbanDefinition, UrbanResponse};
 use rustify::errors::ClientError;
 use std::default::Default;
@@ -6,6 +8,7 @@ use std::default::Default;
 #[derive(Clone)]
 pub struct UrbanDictionaryClient {
     client: reqwest::Client,
+    retry: RetryPolicy,
 }
 
 impl UrbanDictionaryClient {
@@ -22,7 +25,22 @@ impl UrbanDictionaryClient {
     /// let ud = UrbanDictionaryClient::new(client);
     /// ```
     pub fn new(client: reqwest::Client) -> Self {
-        UrbanDictionaryClient { client }
+        UrbanDictionaryClient {
+            client,
+            retry: RetryPolicy::default(),
+        }
+    }
+
+    /// Sets how the client's failed requests are retried, `RetryPolicy::default()` unless set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let ud = UrbanDictionaryClient::default().with_retry(RetryPolicy::default());
+    /// ```
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
     }
 
     /// Creates an ApiClient configured for the Urban Dictionary API using the internal reqwest client.
@@ -43,6 +61,7 @@ impl UrbanDictionaryClient {
                 "https://unofficialurbandictionaryapi.com/api",
                 self.client.clone(),
             ),
+            retry: self.retry.clone(),
         }
     }
 
@@ -90,7 +109,7 @@ impl UrbanDictionaryClient {
 }
 
 impl Default for UrbanDictionaryClient {
-    /// Create an UrbanDictionaryClient configured with a default `reqwest::Client`.
+    /// Create an UrbanDictionaryClient configured with the default `Timeouts`.
     ///
     /// # Examples
     ///
@@ -98,6 +117,6 @@ impl Default for UrbanDictionaryClient {
     /// let _: UrbanDictionaryClient = UrbanDictionaryClient::default();
     /// ```
     fn default() -> Self {
-        UrbanDictionaryClient::new(Default::default())
+        UrbanDictionaryClient::new(Timeouts::default().client())
     }
 }
\ No newline at end of file
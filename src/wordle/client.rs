@@ -1,9 +1,12 @@
 use crate::networking::api_client::ApiClient;
+use crate::networking::retry::RetryPolicy;
+use crate::networking::timeouts::Timeouts;
 use rustify::Endpoint;
 
 #[derive(Clone)]
 pub struct WordleClient {
     client: reqwest::Client,
+    retry: RetryPolicy,
 }
 
 impl WordleClient {
@@ -18,7 +21,25 @@ impl WordleClient {
     /// let wc = crate::WordleClient::new(client);
     /// ```
     pub fn new(client: reqwest::Client) -> WordleClient {
-        WordleClient { client }
+        WordleClient {
+            client,
+            retry: RetryPolicy::default(),
+        }
+    }
+
+    /// Sets how the client's failed requests are retried, `RetryPolicy::default()` unless set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let wc = WordleClient::default().with_retry(RetryPolicy {
+    ///     max_delay: Duration::from_secs(5),
+    ///     ..Default::default()
+    /// });
+    /// ```
+    pub fn with_retry(mut self, retry: RetryPolicy) -> WordleClient {
+        self.retry = retry;
+        self
     }
 
     /// Constructs an ApiClient configured for the NYT Wordle v2 API using the internal HTTP client.
@@ -39,6 +60,7 @@ impl WordleClient {
                 "https://www.nytimes.com/svc/wordle/v2",
                 self.client.clone(),
             ),
+            retry: self.retry.clone(),
         }
     }
 
@@ -77,7 +99,7 @@ impl WordleClient {
 }
 
 impl Default for WordleClient {
-    /// Creates a WordleClient using a default-configured reqwest HTTP client.
+    /// Creates a WordleClient using an HTTP client with the default `Timeouts`.
     ///
     /// # Examples
     ///
@@ -85,6 +107,6 @@ impl Default for WordleClient {
     /// let client = WordleClient::default();
     /// ```
     fn default() -> WordleClient {
-        WordleClient::new(reqwest::Client::new())
+        WordleClient::new(Timeouts::default().client())
     }
 }
\ No newline at end of file